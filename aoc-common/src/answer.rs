@@ -0,0 +1,34 @@
+use crate::parse::ParseError;
+
+/// What a part of a puzzle gives back: a printable answer, or an error for
+/// input that parsed fine but has no answer.
+pub trait Answer {
+    fn answer(self) -> Result<String, ParseError>;
+}
+
+macro_rules! printable {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(self) -> Result<String, ParseError> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+printable!(i32, i64, u32, u64, u128, usize, String);
+
+impl<T: Answer> Answer for Result<T, ParseError> {
+    fn answer(self) -> Result<String, ParseError> {
+        self?.answer()
+    }
+}
+
+/// Parse input and solve one part of it.
+pub fn solve<I, A: Answer>(
+    s: &str,
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    part: impl Fn(&I) -> A,
+) -> Result<String, ParseError> {
+    part(&parse(s)?).answer()
+}
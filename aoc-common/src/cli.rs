@@ -0,0 +1,56 @@
+use std::{env, path::Path, process};
+
+use crate::answer::Answer;
+use crate::input::{Inputs, Source};
+use crate::parse::ParseError;
+
+/// File in the current directory that takes priority over the input cache.
+const INPUT_FILE: &str = "input.txt";
+
+const USAGE: &str = "\
+Usage: cargo run -- [PATH | - | --example N]
+
+Reads the input from PATH, from stdin with -, or uses sample N from the puzzle
+text. Otherwise input.txt is used if it exists, or the input cache if not.";
+
+/// Shared main for every day: parse the day's input and print the answer to
+/// both parts, or print what went wrong and exit with an error code.
+/// The input comes from the source given in the arguments, otherwise from
+/// INPUT_FILE if there is one, otherwise from the cache.
+pub fn main<I, A: Answer, B: Answer>(
+    day: u8,
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    part1: impl Fn(&I) -> A,
+    part2: impl Fn(&I) -> B,
+) {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let source = match Source::from_args(&args.iter().map(String::as_str).collect::<Vec<_>>()) {
+        Ok(Source::Cache) if Path::new(INPUT_FILE).exists() => Source::File(INPUT_FILE.into()),
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let inputs = Inputs::from_env();
+    let input = source.read(day, &inputs).unwrap_or_else(|e| {
+        eprintln!("error: could not read input: {}", e);
+        process::exit(1);
+    });
+
+    let fail = |e: ParseError| -> ! {
+        eprintln!("error: {}: {}", source.describe(day, &inputs), e);
+        process::exit(1);
+    };
+
+    let input = parse(&input).unwrap_or_else(|e| fail(e));
+    println!(
+        "part 1: {}",
+        part1(&input).answer().unwrap_or_else(|e| fail(e))
+    );
+    println!(
+        "part 2: {}",
+        part2(&input).answer().unwrap_or_else(|e| fail(e))
+    );
+}
@@ -0,0 +1,550 @@
+//! The sample inputs from each day's puzzle text, numbered from 1 in the order
+//! they show up there, along with the answers the puzzle text gives for them.
+//!
+//! ```
+//! use aoc_common::fixtures;
+//!
+//! let f = fixtures::fixture(6, 1).unwrap();
+//! assert_eq!("3,4,3,1,2", f.input);
+//! assert_eq!(Some("5934"), f.part1);
+//! ```
+
+use crate::answer::Answer;
+use crate::parse::ParseError;
+
+/// A sample input and its answers. An answer is None when the puzzle text
+/// doesn't give one for that part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixture {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Fixture {
+    /// The answer for part 1 or 2.
+    pub fn answer(&self, part: usize) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+/// Every fixture for a day, or nothing for a day that isn't solved.
+pub const fn fixtures(day: u8) -> &'static [Fixture] {
+    match day {
+        1 => &[
+            Fixture {
+                input: "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263",
+                part1: Some("7"),
+                part2: Some("5"),
+            },
+        ],
+        2 => &[
+            Fixture {
+                input: "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2",
+                part1: Some("150"),
+                part2: Some("900"),
+            },
+        ],
+        3 => &[
+            Fixture {
+                input: "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010",
+                part1: Some("198"),
+                part2: Some("230"),
+            },
+        ],
+        4 => &[
+            Fixture {
+                input: "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+                part1: Some("4512"),
+                part2: Some("1924"),
+            },
+        ],
+        5 => &[
+            Fixture {
+                input: "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+                part1: Some("5"),
+                part2: Some("12"),
+            },
+        ],
+        6 => &[
+            Fixture {
+                input: "3,4,3,1,2",
+                part1: Some("5934"),
+                part2: Some("26984457539"),
+            },
+        ],
+        7 => &[
+            Fixture {
+                input: "16,1,2,0,4,2,7,1,2,14",
+                part1: Some("37"),
+                part2: Some("168"),
+            },
+        ],
+        8 => &[
+            Fixture {
+                input: "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+                part1: None,
+                part2: Some("5353"),
+            },
+            Fixture {
+                input: "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+                part1: Some("26"),
+                part2: Some("61229"),
+            },
+        ],
+        9 => &[
+            Fixture {
+                input: "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678",
+                part1: Some("15"),
+                part2: Some("1134"),
+            },
+        ],
+        10 => &[
+            Fixture {
+                input: "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]",
+                part1: Some("26397"),
+                part2: Some("288957"),
+            },
+        ],
+        11 => &[
+            Fixture {
+                input: "\
+11111
+19991
+19191
+19991
+11111",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+                part1: Some("1656"),
+                part2: Some("195"),
+            },
+        ],
+        12 => &[
+            Fixture {
+                input: "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end",
+                part1: Some("10"),
+                part2: Some("36"),
+            },
+            Fixture {
+                input: "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc",
+                part1: Some("19"),
+                part2: Some("103"),
+            },
+            Fixture {
+                input: "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW",
+                part1: Some("226"),
+                part2: Some("3509"),
+            },
+        ],
+        13 => &[
+            Fixture {
+                input: "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5",
+                part1: Some("17"),
+                part2: Some("\
+#####
+#   #
+#   #
+#   #
+#####"),
+            },
+        ],
+        14 => &[
+            Fixture {
+                input: "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C",
+                part1: Some("1588"),
+                part2: Some("2188189693529"),
+            },
+        ],
+        15 => &[
+            Fixture {
+                input: "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581",
+                part1: Some("40"),
+                part2: Some("315"),
+            },
+        ],
+        16 => &[
+            Fixture {
+                input: "D2FE28",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "38006F45291200",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "EE00D40C823060",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "8A004A801A8002F478",
+                part1: Some("16"),
+                part2: None,
+            },
+            Fixture {
+                input: "620080001611562C8802118E34",
+                part1: Some("12"),
+                part2: None,
+            },
+            Fixture {
+                input: "C0015000016115A2E0802F182340",
+                part1: Some("23"),
+                part2: None,
+            },
+            Fixture {
+                input: "A0016C880162017C3686B18A3D4780",
+                part1: Some("31"),
+                part2: None,
+            },
+            Fixture {
+                input: "C200B40A82",
+                part1: None,
+                part2: Some("3"),
+            },
+            Fixture {
+                input: "04005AC33890",
+                part1: None,
+                part2: Some("54"),
+            },
+            Fixture {
+                input: "880086C3E88112",
+                part1: None,
+                part2: Some("7"),
+            },
+            Fixture {
+                input: "CE00C43D881120",
+                part1: None,
+                part2: Some("9"),
+            },
+            Fixture {
+                input: "D8005AC2A8F0",
+                part1: None,
+                part2: Some("1"),
+            },
+            Fixture {
+                input: "F600BC2D8F",
+                part1: None,
+                part2: Some("0"),
+            },
+            Fixture {
+                input: "9C005AC2F8F0",
+                part1: None,
+                part2: Some("0"),
+            },
+            Fixture {
+                input: "9C0141080250320F1802104A08",
+                part1: None,
+                part2: Some("1"),
+            },
+        ],
+        17 => &[
+            Fixture {
+                input: "target area: x=20..30, y=-10..-5",
+                part1: Some("45"),
+                part2: Some("112"),
+            },
+        ],
+        18 => &[
+            Fixture {
+                input: "[1,1]\n[2,2]\n[3,3]\n[4,4]",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "\
+[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]",
+                part1: None,
+                part2: None,
+            },
+            Fixture {
+                input: "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+                part1: Some("4140"),
+                part2: Some("3993"),
+            },
+        ],
+        _ => &[],
+    }
+}
+
+/// Fixture n (counting from 1) for a day, if there is one.
+pub fn fixture(day: u8, n: usize) -> Option<&'static Fixture> {
+    fixtures(day).get(n.checked_sub(1)?)
+}
+
+/// Sample input n for a day, for tests. Fails to compile in a const if there
+/// isn't one.
+pub const fn input(day: u8, n: usize) -> &'static str {
+    fixtures(day)[n - 1].input
+}
+
+/// Check both parts of a day against every fixture with an answer for them,
+/// panicking on a wrong answer. For tests.
+pub fn check<I, A: Answer, B: Answer>(
+    day: u8,
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    part1: impl Fn(&I) -> A,
+    part2: impl Fn(&I) -> B,
+) {
+    assert!(!fixtures(day).is_empty(), "day {} has no fixtures", day);
+
+    for (i, f) in fixtures(day).iter().enumerate() {
+        if f.part1.is_none() && f.part2.is_none() {
+            continue;
+        }
+
+        let input = parse(f.input).unwrap_or_else(|e| panic!("example {}: {}", i + 1, e));
+        if let Some(expected) = f.part1 {
+            let ans = part1(&input).answer();
+            assert_eq!(Ok(expected.to_string()), ans, "example {} part 1", i + 1);
+        }
+        if let Some(expected) = f.part2 {
+            let ans = part2(&input).answer();
+            assert_eq!(Ok(expected.to_string()), ans, "example {} part 2", i + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_from_one() {
+        assert_eq!(Some("3,4,3,1,2"), fixture(6, 1).map(|f| f.input));
+        assert_eq!(None, fixture(6, 0));
+        assert_eq!(None, fixture(6, 2));
+        assert_eq!(None, fixture(25, 1));
+        assert_eq!(15, fixtures(16).len());
+    }
+
+    #[test]
+    fn every_day_has_answers() {
+        for day in 1..=18 {
+            assert!(
+                fixtures(day).iter().any(|f| f.part1.is_some()),
+                "day {}",
+                day
+            );
+            assert!(
+                fixtures(day).iter().any(|f| f.part2.is_some()),
+                "day {}",
+                day
+            );
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+/// A location on a grid as (x, y), where y is the row and x is the column.
+pub type Point = (usize, usize);
+
+/// Offsets to the points directly above, below, left and right of a point.
+const DELTAS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets to every point surrounding a point, including diagonals.
+const DELTAS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular 2D grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// New grid of the given size with every cell set to fill.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// New grid of the given size where each cell is f of its point.
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Point) -> T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: points(width, height).map(f).collect(),
+        }
+    }
+
+    /// New grid from a vector of rows. Every row must be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "all grid rows must be the same length"
+        );
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Check if a point lies on the grid.
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Get the cell at a point, or None if the point is off the grid.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    /// Get the cell at a point mutably, or None if the point is off the grid.
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point on the grid, row by row. Does not borrow the grid, so the
+    /// grid can be modified while walking over its points.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        points(self.width, self.height)
+    }
+
+    /// Every cell on the grid, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell on the grid mutably, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Each row of the grid from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells in column x from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(if x < self.width { self.height } else { 0 })
+    }
+
+    /// Each column of the grid from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The points directly above, below, left and right of p that are on the
+    /// grid. Like points, this does not borrow the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> {
+        offsets(self.width, self.height, p, &DELTAS4)
+    }
+
+    /// Every point surrounding p (including diagonals) that is on the grid.
+    /// Like points, this does not borrow the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> {
+        offsets(self.width, self.height, p, &DELTAS8)
+    }
+
+    /// Make a new grid of the same size by applying f to every cell.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Convert a point into an index of cells, if it is on the grid.
+    fn index_of(&self, p @ (x, y): Point) -> Option<usize> {
+        self.contains(p).then(|| y * self.width + x)
+    }
+}
+
+impl Grid<u32> {
+    /// Given a grid of single digit numbers in text, turn it into a Grid.
+    pub fn parse_digits(s: &str) -> Result<Grid<u32>, ParseError> {
+        let rows = parse::lines(s, |l| {
+            l.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| ParseError::new(c, "a digit").at_column(i + 1))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        // Every row must be as wide as the first
+        let width = rows.first().map_or(0, |r| r.len());
+        if let Some(y) = rows.iter().position(|r| r.len() != width) {
+            let line = s.lines().nth(y).unwrap_or_default();
+            return Err(ParseError::new(line, format!("a row of {} digits", width)).shifted(y));
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.get(p) {
+            Some(t) => t,
+            None => panic!("point {:?} is outside of the grid", p),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.get_mut(p) {
+            Some(t) => t,
+            None => panic!("point {:?} is outside of the grid", p),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Print every row on its own line, with no spaces between cells. A grid
+    /// made with parse_digits prints the same as its input.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Every point on a width x height grid, row by row.
+fn points(width: usize, height: usize) -> impl Iterator<Item = Point> {
+    (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+}
+
+/// Shift a point by each offset, keeping only the results that land on the grid.
+/// checked_add_signed turns any underflow into a None, so we never wrap around.
+fn offsets(
+    width: usize,
+    height: usize,
+    (x, y): Point,
+    deltas: &'static [(isize, isize)],
+) -> impl Iterator<Item = Point> {
+    deltas
+        .iter()
+        .filter_map(move |&(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+        .filter(move |&(x, y)| x < width && y < height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+123
+456";
+
+    #[test]
+    fn parse() {
+        let g = Grid::parse_digits(SMALL).unwrap();
+
+        assert_eq!((3, 2), (g.width(), g.height()));
+        assert_eq!(Some(&4), g.get((0, 1)));
+        assert_eq!(None, g.get((3, 0)));
+        assert_eq!(6, g[(2, 1)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Grid::parse_digits("123\n4x6").unwrap_err();
+        assert_eq!(ParseError::new('x', "a digit").at_column(2).shifted(1), e);
+
+        let e = Grid::parse_digits("123\n45").unwrap_err();
+        assert_eq!(ParseError::new("45", "a row of 3 digits").shifted(1), e);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(SMALL, Grid::parse_digits(SMALL).unwrap().to_string());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = Grid::parse_digits(SMALL).unwrap();
+
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]],
+            g.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&2, &5], g.column(1).collect::<Vec<_>>());
+        assert_eq!(0, g.column(3).count());
+        assert_eq!(3, g.columns().count());
+    }
+
+    #[test]
+    fn corner_neighbors() {
+        let g = Grid::parse_digits(SMALL).unwrap();
+
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            g.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 1), (1, 0), (1, 1)],
+            g.neighbors8((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 1), (2, 0)],
+            g.neighbors4((2, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn middle_neighbors() {
+        let g = Grid::parse_digits(SMALL).unwrap();
+
+        assert_eq!(
+            vec![(0, 0), (1, 1), (2, 0)],
+            g.neighbors4((1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, g.neighbors8((1, 0)).count());
+    }
+
+    #[test]
+    fn modify_while_walking() {
+        let mut g = Grid::new(2, 2, 0);
+
+        for p in g.points() {
+            for n in g.neighbors4(p) {
+                g[n] += 1;
+            }
+        }
+
+        assert_eq!(Grid::from_fn(2, 2, |_| 2), g);
+    }
+}
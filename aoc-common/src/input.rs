@@ -0,0 +1,405 @@
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::{env, error::Error, fmt, fs};
+
+use crate::fixtures;
+
+/// The year every puzzle in this repo is from.
+pub const YEAR: u16 = 2021;
+
+/// Where puzzle input is downloaded from unless AOC_URL says otherwise.
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Why a day's input could not be found.
+#[derive(Debug)]
+pub enum InputError {
+    /// Reading or writing a file went wrong
+    Io(PathBuf, io::Error),
+    /// The input is not cached and there is no fetcher to download it with
+    NotCached(PathBuf),
+    /// The fetcher could not download the input
+    Fetch(String),
+    /// Reading stdin went wrong
+    Stdin(io::Error),
+    /// The day has no sample input with this number
+    NoExample(u8, usize),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            InputError::NotCached(path) => write!(
+                f,
+                "{} does not exist and AOC_SESSION is not set to download it",
+                path.display()
+            ),
+            InputError::Fetch(e) => write!(f, "could not download input: {}", e),
+            InputError::Stdin(e) => write!(f, "stdin: {}", e),
+            InputError::NoExample(day, n) => match fixtures::fixtures(*day).len() {
+                0 => write!(f, "day {} has no examples", day),
+                len => write!(f, "day {} only has examples 1 to {}, not {}", day, len, n),
+            },
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// Something that can get the puzzle input for a day, usually from the website.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, InputError>;
+}
+
+/// Downloads input over HTTP, logged in with a session token.
+/// https urls are handed to curl, plain http (such as a local test server) is
+/// spoken directly.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    /// New fetcher for the site at base_url, such as "https://adventofcode.com".
+    pub fn new(base_url: &str, session: &str) -> HttpFetcher {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Fetcher using the token in AOC_SESSION, if it is set. AOC_URL replaces
+    /// the website, e.g. with a stand-in server.
+    pub fn from_env() -> Option<HttpFetcher> {
+        let session = env::var("AOC_SESSION").ok().filter(|s| !s.is_empty())?;
+        let url = env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string());
+
+        Some(HttpFetcher::new(&url, &session))
+    }
+
+    /// The url of a day's input.
+    fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    /// GET a plain http url ourselves.
+    fn get_http(&self, url: &str) -> Result<String, InputError> {
+        let rest = url.strip_prefix("http://").unwrap();
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let err = |e: io::Error| InputError::Fetch(format!("{}: {}", url, e));
+
+        let mut stream = TcpStream::connect(host).map_err(err)?;
+        // HTTP/1.0 so the body is never chunked, it just ends when the
+        // connection closes
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: {}\r\nCookie: session={}\r\n\r\n",
+            path, host, self.session
+        )
+        .map_err(err)?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(err)?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| InputError::Fetch(format!("{}: malformed response", url)))?;
+        let status = head.lines().next().unwrap_or_default();
+        if status.split_whitespace().nth(1) != Some("200") {
+            return Err(InputError::Fetch(format!("{}: {}", url, status)));
+        }
+
+        Ok(body.to_string())
+    }
+
+    /// GET an https url with curl. The cookie goes through stdin so the token
+    /// doesn't show up in the process list.
+    fn get_https(&self, url: &str) -> Result<String, InputError> {
+        let err = |e: io::Error| InputError::Fetch(format!("could not run curl: {}", e));
+
+        let mut curl = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--header", "@-", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(err)?;
+
+        if let Some(mut stdin) = curl.stdin.take() {
+            write!(stdin, "Cookie: session={}", self.session).map_err(err)?;
+        }
+
+        let out = curl.wait_with_output().map_err(err)?;
+        if !out.status.success() {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            return Err(InputError::Fetch(stderr.trim().to_string()));
+        }
+
+        String::from_utf8(out.stdout)
+            .map_err(|_| InputError::Fetch(format!("{}: input is not utf-8", url)))
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, InputError> {
+        let url = self.url(day);
+
+        if url.starts_with("http://") {
+            self.get_http(&url)
+        } else {
+            self.get_https(&url)
+        }
+    }
+}
+
+/// Finds puzzle input in a cache directory, downloading and caching anything
+/// that isn't there yet if it has a fetcher.
+pub struct Inputs {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl Inputs {
+    /// Inputs cached in cache_dir, with nothing to download missing ones.
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Inputs {
+        Inputs {
+            cache_dir: cache_dir.into(),
+            fetcher: None,
+        }
+    }
+
+    /// Download missing inputs with fetcher.
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Inputs {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Inputs cached in AOC_CACHE_DIR, or ~/.cache/aoc/2021 by default, fetched
+    /// with HttpFetcher::from_env.
+    pub fn from_env() -> Inputs {
+        let cache_dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                env::var_os("XDG_CACHE_HOME")
+                    .map(PathBuf::from)
+                    .or_else(|| env::var_os("HOME").map(|h| Path::new(&h).join(".cache")))
+                    .unwrap_or_default()
+                    .join("aoc")
+                    .join(YEAR.to_string())
+            });
+
+        let inputs = Inputs::new(cache_dir);
+        match HttpFetcher::from_env() {
+            Some(f) => inputs.with_fetcher(f),
+            None => inputs,
+        }
+    }
+
+    /// The directory inputs are cached in.
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Where a day's input is cached, e.g. day15.txt in the cache directory.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{:02}.txt", day))
+    }
+
+    /// Get a day's input from the cache, downloading it into the cache first
+    /// if needed.
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(s) => return Ok(s),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(InputError::Io(path, e)),
+            Err(_) => (),
+        }
+
+        let fetcher = self
+            .fetcher
+            .as_ref()
+            .ok_or_else(|| InputError::NotCached(path.clone()))?;
+        let input = fetcher.fetch(day)?;
+
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(&path, &input))
+            .map_err(|e| InputError::Io(path, e))?;
+
+        Ok(input)
+    }
+}
+
+/// Where to read a puzzle's input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The input cache (see Inputs)
+    Cache,
+    /// A file
+    File(PathBuf),
+    /// Everything on stdin
+    Stdin,
+    /// One of the sample inputs from the puzzle text, counting from 1
+    Example(usize),
+}
+
+impl Source {
+    /// Pick a source from command line arguments: nothing for the cache,
+    /// `[--input] PATH` for a file, `-` (or `--input -`) for stdin, or
+    /// `--example N`.
+    pub fn from_args(args: &[&str]) -> Result<Source, String> {
+        match args {
+            [] => Ok(Source::Cache),
+            ["-"] | ["--input", "-"] => Ok(Source::Stdin),
+            ["--example", n] => n
+                .parse()
+                .map(Source::Example)
+                .map_err(|_| format!("`{}` is not an example number", n)),
+            [path] | ["--input", path] if !path.starts_with("--") => Ok(Source::File(path.into())),
+            _ => Err(format!("unrecognized arguments `{}`", args.join(" "))),
+        }
+    }
+
+    /// Read a day's input from this source.
+    pub fn read(&self, day: u8, inputs: &Inputs) -> Result<String, InputError> {
+        match self {
+            Source::Cache => inputs.get(day),
+            Source::File(path) => {
+                fs::read_to_string(path).map_err(|e| InputError::Io(path.clone(), e))
+            }
+            Source::Stdin => {
+                let mut s = String::new();
+                io::stdin()
+                    .read_to_string(&mut s)
+                    .map_err(InputError::Stdin)?;
+                Ok(s)
+            }
+            Source::Example(n) => fixtures::fixture(day, *n)
+                .map(|f| f.input.to_string())
+                .ok_or(InputError::NoExample(day, *n)),
+        }
+    }
+
+    /// Describe where a day's input comes from, for error messages.
+    pub fn describe(&self, day: u8, inputs: &Inputs) -> String {
+        match self {
+            Source::Cache => inputs.path(day).display().to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => String::from("stdin"),
+            Source::Example(n) => format!("example {}", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// A fresh, empty directory to cache inputs in.
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Stand-in for the website that answers a single request with the day
+    /// number and session cookie it was asked for, returning its url.
+    fn stand_in_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let lines = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|l| !l.is_empty())
+                .collect::<Vec<_>>();
+
+            let path = lines[0].split_whitespace().nth(1).unwrap().to_string();
+            let cookie = lines
+                .iter()
+                .find_map(|l| l.strip_prefix("Cookie: "))
+                .unwrap();
+            let body = format!("{} {}\n", path, cookie);
+
+            write!(stream, "HTTP/1.0 200 OK\r\n\r\n{}", body).unwrap();
+        });
+
+        format!("http://{}", addr)
+    }
+
+    struct Fixed(&'static str);
+
+    impl Fetcher for Fixed {
+        fn fetch(&self, _: u8) -> Result<String, InputError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn cached() {
+        let dir = temp_cache("cached");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day15.txt"), "1163751742").unwrap();
+
+        let inputs = Inputs::new(&dir).with_fetcher(Fixed("not this"));
+        assert_eq!("1163751742", inputs.get(15).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn not_cached() {
+        let dir = temp_cache("not-cached");
+        let inputs = Inputs::new(&dir);
+
+        assert!(
+            matches!(inputs.get(3), Err(InputError::NotCached(p)) if p == dir.join("day03.txt"))
+        );
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let dir = temp_cache("fetch");
+        let inputs = Inputs::new(&dir).with_fetcher(HttpFetcher::new(&stand_in_server(), "abc123"));
+
+        let expected = "/2021/day/6/input session=abc123\n";
+        assert_eq!(expected, inputs.get(6).unwrap());
+
+        // The server only answers once, so this has to come from the cache
+        assert_eq!(expected, inputs.get(6).unwrap());
+        assert_eq!(expected, fs::read_to_string(dir.join("day06.txt")).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(Ok(Source::Cache), Source::from_args(&[]));
+        assert_eq!(Ok(Source::Stdin), Source::from_args(&["-"]));
+        assert_eq!(Ok(Source::Stdin), Source::from_args(&["--input", "-"]));
+        assert_eq!(
+            Ok(Source::Example(2)),
+            Source::from_args(&["--example", "2"])
+        );
+        assert_eq!(
+            Ok(Source::File("in.txt".into())),
+            Source::from_args(&["--input", "in.txt"])
+        );
+        assert!(Source::from_args(&["--example", "two"]).is_err());
+        assert!(Source::from_args(&["--exmaple", "2"]).is_err());
+
+        let inputs = Inputs::new(temp_cache("sources"));
+        assert_eq!("3,4,3,1,2", Source::Example(1).read(6, &inputs).unwrap());
+        assert!(matches!(
+            Source::Example(2).read(6, &inputs),
+            Err(InputError::NoExample(6, 2))
+        ));
+    }
+}
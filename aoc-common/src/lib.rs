@@ -0,0 +1,8 @@
+//! Helpers shared between the solutions of multiple days.
+
+pub mod answer;
+pub mod cli;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod parse;
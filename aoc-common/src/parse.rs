@@ -0,0 +1,148 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Where and why puzzle input did not match what a parser expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of input the error is on, starting at 1, or None if the error is
+    /// about the input as a whole
+    pub line: Option<usize>,
+    /// Character in the line the error starts at, starting at 1, if known
+    pub column: Option<usize>,
+    /// The piece of input that could not be parsed
+    pub token: String,
+    /// Description of what should have been there instead
+    pub expected: String,
+}
+
+impl ParseError {
+    /// New error on the first line of input. Parsers that only see part of the
+    /// input report errors relative to that part, and the caller moves them to
+    /// the right line with shifted.
+    pub fn new(token: impl ToString, expected: impl ToString) -> ParseError {
+        ParseError {
+            line: Some(1),
+            column: None,
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// New error about the input as a whole rather than anything on one line,
+    /// like input that parses fine but has no answer. It has no token and
+    /// prints as just the message.
+    pub fn whole(message: impl ToString) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            token: String::new(),
+            expected: message.to_string(),
+        }
+    }
+
+    /// Note which character of the line the error starts at.
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column = Some(column);
+        self
+    }
+
+    /// Move the error down n lines, for when the input it was found in starts
+    /// n lines into the whole puzzle input.
+    pub fn shifted(mut self, n: usize) -> ParseError {
+        self.line = self.line.map(|l| l + n);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(line) = self.line else {
+            return write!(f, "{}", self.expected);
+        };
+
+        write!(f, "line {}", line)?;
+        if let Some(c) = self.column {
+            write!(f, ", column {}", c)?;
+        }
+
+        if self.token.is_empty() {
+            write!(f, ": expected {}, found nothing", self.expected)
+        } else {
+            write!(f, ": expected {}, found `{}`", self.expected, self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a single token, describing it as expected if it does not parse.
+pub fn token<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, expected))
+}
+
+/// Run f over every line of input, moving any error it returns to the line it
+/// came from.
+pub fn lines<'a, T>(
+    s: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.shifted(i)))
+        .collect()
+}
+
+/// Convert a string of numbers separated by commas into a vector.
+/// Surrounding whitespace (such as a trailing newline) is ignored.
+pub fn comma_separated<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    let s = s.trim();
+    let mut column = 1;
+
+    s.split(',')
+        .map(|i| {
+            let n = token(i.trim(), "a number").map_err(|e| e.at_column(column));
+            column += i.chars().count() + 1;
+            n
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Ok(vec![3, 4, 3, 1, 2]), comma_separated::<i32>("3,4,3,1,2"));
+    }
+
+    #[test]
+    fn trailing_newline() {
+        assert_eq!(Ok(vec![16, 1, 2]), comma_separated::<u64>("16,1,2\n"));
+    }
+
+    #[test]
+    fn bad_number() {
+        let e = comma_separated::<u64>("16,1,x2").unwrap_err();
+
+        assert_eq!(ParseError::new("x2", "a number").at_column(6), e);
+        assert_eq!(
+            "line 1, column 6: expected a number, found `x2`",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn line_numbers() {
+        let e = lines("1\n2\nthree", |l| token::<u32>(l, "a number")).unwrap_err();
+
+        assert_eq!(Some(3), e.line);
+    }
+
+    #[test]
+    fn whole_input() {
+        let e = lines("1\n2", |_| Err::<u32, _>(ParseError::whole("no answer"))).unwrap_err();
+
+        assert_eq!(None, e.line);
+        assert_eq!("no answer", e.to_string());
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::{fs, io, path::Path};
+
+use aoc_common::parse::ParseError;
+
+use crate::puzzle::PuzzleId;
+
+/// Hash of a puzzle input, so answers are only compared against answers for
+/// the same input.
+pub type InputHash = u64;
+
+/// Hash an input with 64 bit FNV-1a. Doesn't need to be secure, just stable
+/// between runs and versions of Rust, which std's hashers don't promise.
+pub fn hash(input: &str) -> InputHash {
+    input.bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Recorded answers, stored in a small subset of TOML: a table per puzzle,
+/// mapping input hashes to the answer for that input.
+///
+/// ```toml
+/// [day15b]
+/// "9f86d081884c7d65" = "2809"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<PuzzleId, BTreeMap<InputHash, String>>,
+}
+
+impl Answers {
+    /// Load answers from a file, or no answers if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse().map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    /// Write every answer to a file, replacing it.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// The recorded answer for a puzzle's input, if there is one.
+    pub fn get(&self, id: PuzzleId, input: InputHash) -> Option<&str> {
+        self.answers.get(&id)?.get(&input).map(String::as_str)
+    }
+
+    /// Record the answer for a puzzle's input, replacing any old answer.
+    pub fn insert(&mut self, id: PuzzleId, input: InputHash, answer: String) {
+        self.answers.entry(id).or_default().insert(input, answer);
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        let mut table = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let err = |expected| ParseError::new(line, expected).shifted(i);
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                table = Some(name.parse().map_err(|_| err("a puzzle like `[day15b]`"))?);
+            } else {
+                let id = table.ok_or_else(|| err("a `[dayNNx]` table first"))?;
+
+                let (hash, answer) = line
+                    .split_once('=')
+                    .and_then(|(h, a)| Some((unquote(h)?, unquote(a)?)))
+                    .ok_or_else(|| err("an answer like `\"<input hash>\" = \"<answer>\"`"))?;
+                let hash = u64::from_str_radix(&hash, 16).map_err(|_| err("a hex input hash"))?;
+
+                answers.insert(id, hash, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Answers recorded by `aoc verify`, by puzzle and input hash"
+        )?;
+
+        for (id, answers) in &self.answers {
+            write!(f, "\n[{}]\n", id)?;
+            for (hash, answer) in answers {
+                writeln!(f, "\"{:016x}\" = \"{}\"", hash, escape(answer))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Escape the characters a TOML string can't hold as they are. Anything else,
+/// like non-ASCII letters, is written as it is.
+fn escape(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Take the quotes off of a TOML string, undoing the escapes Display adds.
+/// Multi line answers (like day13b's) are stored with their newlines escaped.
+fn unquote(s: &str) -> Option<String> {
+    let s = s.trim().strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        out.push(match chars.next()? {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            c @ ('\\' | '"' | '\'') => c,
+            _ => return None,
+        });
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(0xcbf29ce484222325, hash(""));
+        assert_eq!(0xaf63df4c8601f1a5, hash("b"));
+        assert_ne!(hash("3,4,3,1,2"), hash("3,4,3,1,2\n"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut a = Answers::default();
+        a.insert(
+            "6b".parse().unwrap(),
+            hash("3,4,3,1,2"),
+            "26984457539".into(),
+        );
+        a.insert("13b".parse().unwrap(), 1, "# #\n ##".into());
+        a.insert("6a".parse().unwrap(), 2, "5934".into());
+        a.insert("8b".parse().unwrap(), 3, "é \"q\"\t\\".into());
+
+        assert_eq!(Ok(&a), a.to_string().parse().as_ref());
+        assert!(a.to_string().contains(r#""é \"q\"\t\\""#));
+        assert_eq!(Some("# #\n ##"), a.get("13b".parse().unwrap(), 1));
+        assert_eq!(None, a.get("13b".parse().unwrap(), 2));
+    }
+
+    #[test]
+    fn bad_store() {
+        let e = "[day06b]\n\"00ff\" = 26".parse::<Answers>().unwrap_err();
+        assert_eq!(Some(2), e.line);
+
+        let e = "\"00ff\" = \"26\"".parse::<Answers>().unwrap_err();
+        assert_eq!("a `[dayNNx]` table first", e.expected);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::puzzle::PuzzleId;
+
+/// Stop taking samples once this much time has been spent on one benchmark...
+const TIME_BUDGET: Duration = Duration::from_secs(2);
+/// ...unless there are fewer than this many samples...
+const MIN_SAMPLES: usize = 5;
+/// ...and never take more than this many.
+const MAX_SAMPLES: usize = 200;
+
+/// How long something took over every sample of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    pub median: Duration,
+    pub min: Duration,
+    pub samples: usize,
+}
+
+impl Timing {
+    /// Summarize a set of sample times. There must be at least one.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        samples.sort_unstable();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Timing {
+            median,
+            min: samples[0],
+            samples: samples.len(),
+        }
+    }
+}
+
+/// Time f, running it once to warm up and then as many times as fit in
+/// TIME_BUDGET (within MIN_SAMPLES and MAX_SAMPLES).
+pub fn measure<T>(mut f: impl FnMut() -> T) -> Timing {
+    black_box(f());
+
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    while samples.len() < MIN_SAMPLES
+        || (samples.len() < MAX_SAMPLES && start.elapsed() < TIME_BUDGET)
+    {
+        let t = Instant::now();
+        black_box(f());
+        samples.push(t.elapsed());
+    }
+
+    Timing::from_samples(samples)
+}
+
+/// Print a duration with a sensible unit, like "15.2ms".
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos() as f64;
+
+    if ns < 1e3 {
+        format!("{:.0}ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.1}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.1}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+/// Every median time recorded by past benchmarks, one line per puzzle per run
+/// as "<unix time>\t<puzzle>\t<median in nanoseconds>".
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    latest: HashMap<PuzzleId, Duration>,
+}
+
+impl History {
+    /// Load the history file, or an empty history if there isn't one yet.
+    /// Lines that can't be read are skipped, since old results aren't worth
+    /// failing a benchmark over.
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(s) => Ok(History::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse(s: &str) -> History {
+        let mut latest = HashMap::new();
+
+        for line in s.lines() {
+            if let [_, id, ns] = line.split('\t').collect::<Vec<_>>()[..] {
+                if let (Ok(id), Ok(ns)) = (id.parse(), ns.parse()) {
+                    // Later lines are newer, so they win
+                    latest.insert(id, Duration::from_nanos(ns));
+                }
+            }
+        }
+
+        History { latest }
+    }
+
+    /// The median time from the most recent benchmark of a puzzle.
+    pub fn latest(&self, id: PuzzleId) -> Option<Duration> {
+        self.latest.get(&id).copied()
+    }
+
+    /// Add a benchmark result to the end of the history file.
+    pub fn append(path: &Path, id: PuzzleId, t: &Timing) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(f, "{}\t{}\t{}", now, id, t.median.as_nanos())
+    }
+}
+
+/// Describe how much faster or slower now is than before, like "-12.5%".
+pub fn format_change(before: Duration, now: Duration) -> String {
+    let change = (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median() {
+        let ms = |v: &[u64]| v.iter().map(|&i| Duration::from_millis(i)).collect();
+
+        let t = Timing::from_samples(ms(&[5, 1, 3]));
+        assert_eq!(Duration::from_millis(3), t.median);
+        assert_eq!(Duration::from_millis(1), t.min);
+
+        let t = Timing::from_samples(ms(&[4, 1, 3, 8]));
+        assert_eq!(Duration::from_micros(3500), t.median);
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("15.2µs", format_duration(Duration::from_nanos(15_200)));
+        assert_eq!("3.0ms", format_duration(Duration::from_millis(3)));
+        assert_eq!("1.50s", format_duration(Duration::from_millis(1500)));
+
+        let ms = Duration::from_millis;
+        assert_eq!("-50.0%", format_change(ms(4), ms(2)));
+        assert_eq!("+25.0%", format_change(ms(4), ms(5)));
+    }
+
+    #[test]
+    fn latest_wins() {
+        let h = History::parse("1\tday15b\t900\n2\tday15b\t300\nbad line\n2\tday01a\t7");
+
+        assert_eq!(
+            Some(Duration::from_nanos(300)),
+            h.latest("15b".parse().unwrap())
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(7)),
+            h.latest("1a".parse().unwrap())
+        );
+        assert_eq!(None, h.latest("2a".parse().unwrap()));
+    }
+}
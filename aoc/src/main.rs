@@ -0,0 +1,265 @@
+use std::{env, process};
+
+use aoc::answers::{self, Answers};
+use aoc::bench::{self, History};
+use aoc::puzzle::{self, Part, Puzzle, PuzzleId};
+use aoc::select::Selection;
+use aoc_common::fixtures;
+use aoc_common::input::{Inputs, Source};
+
+const USAGE: &str = "\
+Usage: aoc run <PUZZLES> [--input <PATH> | --example <N>]
+       aoc verify [PUZZLES] [--update]
+       aoc bench [PUZZLES]
+
+run prints the answer to each puzzle. verify checks each answer against the
+one recorded for the same input in answers.toml in the cache directory,
+recording any new answers. Changed answers are reported and only replace the
+recorded ones with --update. bench times each puzzle, comparing the median
+time against the last benchmark recorded in bench_history.tsv in the cache
+directory, then records the new times. Use a release build for benchmarks.
+
+PUZZLES is one of:
+    all      every puzzle (the default for verify and bench)
+    15       both parts of day 15
+    3-7      both parts of days 3 through 7
+    15b      only day 15 part b
+
+By default each day's input is read from the cache, ~/.cache/aoc/2021/day15.txt
+(or AOC_CACHE_DIR/day15.txt). Anything missing is downloaded into the cache if
+AOC_SESSION holds the session cookie from the website. --input overrides this
+when running a single puzzle, reading a file or stdin for `--input -`.
+--example runs each puzzle on sample N from its puzzle text instead, checking
+the answer against the one the puzzle text gives.";
+
+/// File in the cache directory that answers are recorded in.
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// File in the cache directory that benchmark times are recorded in.
+const BENCH_FILE: &str = "bench_history.tsv";
+
+fn main() {
+    match parse_args(env::args().skip(1).collect()) {
+        Ok(0) => (),
+        Ok(_) => process::exit(1),
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+/// Parse the command line and run the command it asks for, returning how many
+/// puzzles failed.
+fn parse_args(args: Vec<String>) -> Result<usize, String> {
+    match &args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", sel] => run(sel.parse()?, Source::Cache),
+        ["run", sel, flag @ ("--input" | "--example"), arg] => {
+            run(sel.parse()?, Source::from_args(&[flag, arg])?)
+        }
+        ["verify"] => verify(Selection::All, false),
+        ["verify", "--update"] => verify(Selection::All, true),
+        ["verify", sel] => verify(sel.parse()?, false),
+        ["verify", sel, "--update"] => verify(sel.parse()?, true),
+        ["bench"] => bench_all(Selection::All),
+        ["bench", sel] => bench_all(sel.parse()?),
+        [] => Err(String::from("no command given")),
+        _ => Err(format!("unrecognized arguments `{}`", args.join(" "))),
+    }
+}
+
+/// Every selected puzzle, along with the input for it (or why there isn't any)
+/// and where that input came from.
+fn with_input<'a>(
+    puzzles: &'a [Puzzle],
+    selection: &'a Selection,
+    inputs: &'a Inputs,
+    source: &'a Source,
+) -> impl Iterator<Item = (&'a Puzzle, String, Result<String, String>)> + 'a {
+    puzzles
+        .iter()
+        .filter(move |p| selection.contains(&p.id))
+        .map(move |p| {
+            (
+                p,
+                source.describe(p.id.day, inputs),
+                source.read(p.id.day, inputs).map_err(|e| e.to_string()),
+            )
+        })
+}
+
+/// Run every selected puzzle, returning how many had input they could not
+/// parse or got an example's answer wrong.
+fn run(selection: Selection, source: Source) -> Result<usize, String> {
+    if matches!(source, Source::File(_) | Source::Stdin) && !matches!(selection, Selection::One(_))
+    {
+        return Err(String::from(
+            "--input can only be used with a single puzzle",
+        ));
+    }
+
+    let puzzles = puzzle::all();
+    let inputs = Inputs::from_env();
+
+    let mut failed = 0;
+    for (p, from, s) in with_input(&puzzles, &selection, &inputs, &source) {
+        match s.map(|s| p.solver.solve(&s)) {
+            Ok(Ok(ans)) => match expected(p.id, &source) {
+                Some(exp) if exp != ans => {
+                    println!("{}: {}, but expected {}", p.id, ans, exp);
+                    failed += 1;
+                }
+                Some(_) => println!("{}: {} (as expected)", p.id, ans),
+                None => println!("{}: {}", p.id, ans),
+            },
+            Ok(Err(e)) => {
+                println!("{}: error in {}: {}", p.id, from, e);
+                failed += 1;
+            }
+            // A missing cached input just hasn't been downloaded yet, but an
+            // input the user asked for has to be there
+            Err(e) if matches!(source, Source::Cache) => {
+                println!("{}: skipped, could not read input ({})", p.id, e)
+            }
+            Err(e) => {
+                eprintln!("{}: could not read input ({})", p.id, e);
+                failed += 1;
+            }
+        }
+    }
+
+    Ok(failed)
+}
+
+/// The answer the puzzle text gives for a puzzle's input, if it's an example.
+fn expected(id: PuzzleId, source: &Source) -> Option<&'static str> {
+    let part = match id.part {
+        Part::A => 1,
+        Part::B => 2,
+    };
+
+    match source {
+        Source::Example(n) => fixtures::fixture(id.day, *n)?.answer(part),
+        _ => None,
+    }
+}
+
+/// Check every selected puzzle's answer against the recorded one, returning
+/// how many changed or failed to parse their input.
+fn verify(selection: Selection, update: bool) -> Result<usize, String> {
+    let puzzles = puzzle::all();
+    let inputs = Inputs::from_env();
+
+    let answers_path = inputs.cache_dir().join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut changed_store = false;
+
+    let mut failed = 0;
+    for (p, from, s) in with_input(&puzzles, &selection, &inputs, &Source::Cache) {
+        let s = match s {
+            Ok(s) => s,
+            Err(e) => {
+                println!("{}: skipped, could not read input ({})", p.id, e);
+                continue;
+            }
+        };
+
+        let ans = match p.solver.solve(&s) {
+            Ok(ans) => ans,
+            Err(e) => {
+                println!("{}: error in {}: {}", p.id, from, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let hash = answers::hash(&s);
+        match answers.get(p.id, hash) {
+            Some(old) if old == ans => println!("{}: ok", p.id),
+            Some(old) => {
+                println!("{}: CHANGED from {:?} to {:?}", p.id, old, ans);
+                if update {
+                    answers.insert(p.id, hash, ans);
+                    changed_store = true;
+                } else {
+                    failed += 1;
+                }
+            }
+            None => {
+                println!("{}: recorded {:?}", p.id, ans);
+                answers.insert(p.id, hash, ans);
+                changed_store = true;
+            }
+        }
+    }
+
+    if changed_store {
+        answers
+            .save(&answers_path)
+            .map_err(|e| format!("could not save {}: {}", answers_path.display(), e))?;
+    }
+
+    Ok(failed)
+}
+
+/// Time every selected puzzle and print a table of the results, returning how
+/// many had input they could not parse.
+fn bench_all(selection: Selection) -> Result<usize, String> {
+    let puzzles = puzzle::all();
+    let inputs = Inputs::from_env();
+
+    let history_path = inputs.cache_dir().join(BENCH_FILE);
+    let history = History::load(&history_path)
+        .map_err(|e| format!("could not read {}: {}", history_path.display(), e))?;
+
+    println!(
+        "{:<8} {:>10} {:>10} {:>7} {:>10} {:>8}",
+        "puzzle", "median", "min", "runs", "previous", "change"
+    );
+
+    let mut failed = 0;
+    for (p, from, s) in with_input(&puzzles, &selection, &inputs, &Source::Cache) {
+        let s = match s {
+            Ok(s) => s,
+            Err(e) => {
+                println!(
+                    "{:<8} skipped, could not read input ({})",
+                    p.id.to_string(),
+                    e
+                );
+                continue;
+            }
+        };
+
+        // Don't time something that can't get an answer
+        if let Err(e) = p.solver.solve(&s) {
+            println!("{:<8} error in {}: {}", p.id.to_string(), from, e);
+            failed += 1;
+            continue;
+        }
+
+        let t = bench::measure(|| p.solver.solve(&s));
+        let (previous, change) = match history.latest(p.id) {
+            Some(before) => (
+                bench::format_duration(before),
+                bench::format_change(before, t.median),
+            ),
+            None => (String::from("-"), String::from("-")),
+        };
+
+        println!(
+            "{:<8} {:>10} {:>10} {:>7} {:>10} {:>8}",
+            p.id.to_string(),
+            bench::format_duration(t.median),
+            bench::format_duration(t.min),
+            t.samples,
+            previous,
+            change
+        );
+
+        History::append(&history_path, p.id, &t)
+            .map_err(|e| format!("could not save {}: {}", history_path.display(), e))?;
+    }
+
+    Ok(failed)
+}
@@ -0,0 +1,150 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use aoc_common::answer::solve;
+use aoc_common::parse::ParseError;
+
+/// Which half of a day's puzzle is being solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+/// Names a single puzzle, such as day 15 part B.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub day: u8,
+    pub part: Part,
+}
+
+impl FromStr for PuzzleId {
+    type Err = String;
+
+    /// Parse a puzzle name like "15b", "5a" or "day15b".
+    fn from_str(s: &str) -> Result<PuzzleId, String> {
+        let name = s.strip_prefix("day").unwrap_or(s);
+
+        let part = match name.chars().last() {
+            Some('a') => Part::A,
+            Some('b') => Part::B,
+            _ => return Err(format!("puzzle `{}` must end in `a` or `b`", s)),
+        };
+
+        let day = name[..name.len() - 1]
+            .parse()
+            .map_err(|_| format!("puzzle `{}` does not start with a day number", s))?;
+
+        Ok(PuzzleId { day, part })
+    }
+}
+
+impl Display for PuzzleId {
+    /// Print with the puzzle's crate and part, e.g. "day15b"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Part::A => 'a',
+            Part::B => 'b',
+        };
+
+        write!(f, "day{:02}{}", self.day, part)
+    }
+}
+
+/// Anything that can turn puzzle input into an answer.
+pub trait Solver {
+    /// Solve the puzzle for the given input, returning the answer as it should
+    /// be printed, or where the input could not be understood.
+    fn solve(&self, input: &str) -> Result<String, ParseError>;
+}
+
+/// A function from the input to something printable is all a Solver needs to
+/// be.
+impl<F, T> Solver for F
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+    T: Display,
+{
+    fn solve(&self, input: &str) -> Result<String, ParseError> {
+        self(input).map(|ans| ans.to_string())
+    }
+}
+
+/// A puzzle along with the solver for it.
+pub struct Puzzle {
+    pub id: PuzzleId,
+    pub solver: Box<dyn Solver>,
+}
+
+impl Puzzle {
+    fn new(day: u8, part: Part, solver: impl Solver + 'static) -> Puzzle {
+        Puzzle {
+            id: PuzzleId { day, part },
+            solver: Box::new(solver),
+        }
+    }
+}
+
+/// Register both parts of each day's crate.
+macro_rules! puzzles {
+    ($($day:literal => $krate:ident;)*) => {
+        vec![$(
+            Puzzle::new($day, Part::A, |s: &str| solve(s, $krate::parse, $krate::part1)),
+            Puzzle::new($day, Part::B, |s: &str| solve(s, $krate::parse, $krate::part2)),
+        )*]
+    };
+}
+
+/// Every puzzle that has been solved, in order of day and part.
+pub fn all() -> Vec<Puzzle> {
+    puzzles! {
+        1 => day01;
+        2 => day02;
+        3 => day03;
+        4 => day04;
+        5 => day05;
+        6 => day06;
+        7 => day07;
+        8 => day08;
+        9 => day09;
+        10 => day10;
+        11 => day11;
+        12 => day12;
+        13 => day13;
+        14 => day14;
+        15 => day15;
+        16 => day16;
+        17 => day17;
+        18 => day18;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id() {
+        let id = PuzzleId {
+            day: 15,
+            part: Part::B,
+        };
+
+        assert_eq!(Ok(id), "15b".parse());
+        assert_eq!(Ok(id), "day15b".parse());
+        assert_eq!("day15b", id.to_string());
+
+        assert!("15".parse::<PuzzleId>().is_err());
+        assert!("b".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn solve_sample() {
+        let p = all().into_iter().find(|p| p.id.to_string() == "day06b");
+
+        assert_eq!(
+            Ok(String::from("26984457539")),
+            p.unwrap().solver.solve("3,4,3,1,2")
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::puzzle::PuzzleId;
+
+/// Which puzzles the user asked to run.
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    /// Every puzzle
+    All,
+    /// Both parts of every day in the range
+    Days(RangeInclusive<u8>),
+    /// A single part of a single day
+    One(PuzzleId),
+}
+
+impl Selection {
+    /// Check if a puzzle is part of this selection.
+    pub fn contains(&self, id: &PuzzleId) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Days(r) => r.contains(&id.day),
+            Selection::One(i) => i == id,
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    /// Parse "all", a day ("15"), a range of days ("3-7") or a single puzzle
+    /// ("15b").
+    fn from_str(s: &str) -> Result<Selection, String> {
+        let day = |d: &str| {
+            d.parse::<u8>()
+                .map_err(|_| format!("`{}` is not a day number", d))
+        };
+
+        if s == "all" {
+            Ok(Selection::All)
+        } else if let Some((start, end)) = s.split_once('-') {
+            Ok(Selection::Days(day(start)?..=day(end)?))
+        } else if s.ends_with(|c: char| c.is_ascii_digit()) {
+            let d = day(s)?;
+            Ok(Selection::Days(d..=d))
+        } else {
+            Ok(Selection::One(s.parse()?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::Part;
+
+    #[test]
+    fn parse_selection() {
+        assert_eq!(Ok(Selection::All), "all".parse());
+        assert_eq!(Ok(Selection::Days(3..=7)), "3-7".parse());
+        assert_eq!(Ok(Selection::Days(9..=9)), "9".parse());
+        assert_eq!(
+            Ok(Selection::One(PuzzleId {
+                day: 15,
+                part: Part::B
+            })),
+            "15b".parse()
+        );
+
+        assert!("3-x".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn range_contains() {
+        let s = Selection::Days(3..=7);
+
+        assert!(s.contains(&"3a".parse().unwrap()));
+        assert!(s.contains(&"7b".parse().unwrap()));
+        assert!(!s.contains(&"8a".parse().unwrap()));
+    }
+}
@@ -0,0 +1,179 @@
+use std::cmp::{max, Ordering};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use aoc_common::parse::{self, ParseError};
+
+/// Every depth the sonar measured, in order.
+pub type Input = Vec<i32>;
+
+/// Turn string of numbers into vector of depths
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    parse::lines(s, |i| parse::token(i, "a depth"))
+}
+
+/// Count the number of times the depth increases.
+pub fn part1(v: &Input) -> usize {
+    sweep(v.iter().copied(), 1).increases
+}
+
+/// Count the number of times the sum of a three measurement sliding window
+/// increases.
+pub fn part2(v: &Input) -> usize {
+    sweep(v.iter().copied(), 3).increases
+}
+
+/// What happened between every pair of neighbouring windows in a sweep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// The most increases in a row
+    pub longest_rise: usize,
+}
+
+/// Running stats over a sonar sweep, fed one depth at a time so the whole
+/// sweep never has to be in memory.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    window: usize,
+    /// The last window depths
+    recent: VecDeque<i32>,
+    stats: Stats,
+    rise: usize,
+}
+
+impl Sweep {
+    /// Compare sliding windows of window depths. Panics if window is 0, since
+    /// there would be nothing to compare.
+    pub fn new(window: usize) -> Sweep {
+        assert!(window > 0, "a sliding window needs at least one depth");
+
+        Sweep {
+            window,
+            recent: VecDeque::with_capacity(window + 1),
+            stats: Stats::default(),
+            rise: 0,
+        }
+    }
+
+    /// Take the next depth of the sweep.
+    pub fn push(&mut self, depth: i32) {
+        self.recent.push_back(depth);
+        if self.recent.len() <= self.window {
+            return;
+        }
+
+        // the two windows share every depth but the oldest and the newest, so
+        // comparing those two is the same as comparing the sums
+        let oldest = self.recent.pop_front().unwrap();
+        match depth.cmp(&oldest) {
+            Ordering::Greater => {
+                self.stats.increases += 1;
+                self.rise += 1;
+                self.stats.longest_rise = max(self.rise, self.stats.longest_rise);
+            }
+            Ordering::Less => {
+                self.stats.decreases += 1;
+                self.rise = 0;
+            }
+            Ordering::Equal => {
+                self.stats.plateaus += 1;
+                self.rise = 0;
+            }
+        }
+    }
+
+    /// The stats of every depth pushed so far.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+/// Get the stats of a whole sweep, comparing sliding windows of window depths.
+pub fn sweep(depths: impl IntoIterator<Item = i32>, window: usize) -> Stats {
+    let mut s = Sweep::new(window);
+    for d in depths {
+        s.push(d);
+    }
+    s.stats()
+}
+
+/// Reading a sweep from a reader went wrong.
+#[derive(Debug)]
+pub enum SweepError {
+    /// The reader failed
+    Io(io::Error),
+    /// A line was not a depth
+    Parse(ParseError),
+}
+
+impl fmt::Display for SweepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SweepError::Io(e) => write!(f, "could not read sweep: {}", e),
+            SweepError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for SweepError {}
+
+/// Like sweep, but reading one depth per line from r as it goes.
+pub fn read_sweep(r: impl BufRead, window: usize) -> Result<Stats, SweepError> {
+    let mut s = Sweep::new(window);
+    for (i, line) in r.lines().enumerate() {
+        let line = line.map_err(SweepError::Io)?;
+        let depth = parse::token(&line, "a depth").map_err(|e| SweepError::Parse(e.shifted(i)))?;
+        s.push(depth);
+    }
+    Ok(s.stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_example() {
+        fixtures::check(1, parse, part1, part2);
+    }
+
+    #[test]
+    fn stats() {
+        let sample = fixtures::input(1, 1);
+
+        let ans = Stats {
+            increases: 7,
+            decreases: 2,
+            plateaus: 0,
+            longest_rise: 3,
+        };
+        assert_eq!(ans, read_sweep(sample.as_bytes(), 1).unwrap());
+
+        let ans = Stats {
+            increases: 5,
+            decreases: 1,
+            plateaus: 1,
+            longest_rise: 4,
+        };
+        assert_eq!(ans, read_sweep(sample.as_bytes(), 3).unwrap());
+
+        // a window as long as the sweep has nothing to compare against
+        assert_eq!(Stats::default(), sweep(parse(sample).unwrap(), 10));
+    }
+
+    #[test]
+    fn bad_depth() {
+        match read_sweep("199\n200\n2o8".as_bytes(), 1) {
+            Err(SweepError::Parse(e)) => {
+                assert_eq!("line 3: expected a depth, found `2o8`", e.to_string())
+            }
+            r => panic!("expected a parse error, got {:?}", r),
+        }
+    }
+}
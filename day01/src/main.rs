@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(1, day01::parse, day01::part1, day01::part2);
+}
@@ -0,0 +1,278 @@
+use aoc_common::parse::{self, ParseError};
+
+/// Starts a comment that runs to the end of the line.
+const COMMENT: char = '#';
+
+/// The planned course.
+pub type Input = Vec<Update>;
+
+/// One line of the course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Update {
+    Move(Move),
+    /// Go back to where the course started
+    Reset,
+}
+
+/// A command that moves the submarine, which a Movement decides the meaning of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Forward(i32),
+    Back(i32),
+    Up(i32),
+    Down(i32),
+}
+
+/// Parse a string of updates into a vector of updates, skipping comments and
+/// blank lines
+pub fn parse(i: &str) -> Result<Input, ParseError> {
+    Ok(parse::lines(i, str_to_update)?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Follow the planned course with up and down changing the depth, and
+/// multiply the final horizontal position by the final depth.
+pub fn part1(updates: &Input) -> i32 {
+    follow(updates, &Plain).product()
+}
+
+/// Follow the planned course with up and down changing the aim, and multiply
+/// the final horizontal position by the final depth.
+pub fn part2(updates: &Input) -> i32 {
+    follow(updates, &Aim).product()
+}
+
+/// Turn a single update string into an Update, or None if there's only a
+/// comment on the line
+fn str_to_update(s: &str) -> Result<Option<Update>, ParseError> {
+    let command = s.split(COMMENT).next().unwrap();
+
+    // The amount is always the last thing before any comment
+    let amount = |i: &str| {
+        parse::token(i, "a whole number").map_err(|e| e.at_column(command.rfind(i).unwrap() + 1))
+    };
+
+    match command.split_whitespace().collect::<Vec<_>>()[..] {
+        [] => Ok(None),
+        ["forward", i] => Ok(Some(Update::Move(Move::Forward(amount(i)?)))),
+        ["back", i] => Ok(Some(Update::Move(Move::Back(amount(i)?)))),
+        ["down", i] => Ok(Some(Update::Move(Move::Down(amount(i)?)))),
+        ["up", i] => Ok(Some(Update::Move(Move::Up(amount(i)?)))),
+        ["reset"] => Ok(Some(Update::Reset)),
+        _ => Err(ParseError::new(
+            command.trim(),
+            "`forward`, `back`, `down` or `up` followed by a number, or `reset`",
+        )),
+    }
+}
+
+/// Where the submarine is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horiz: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+impl Position {
+    /// The horizontal position multiplied by the depth
+    pub fn product(&self) -> i32 {
+        self.horiz * self.depth
+    }
+}
+
+/// What a move does to the submarine's position.
+pub trait Movement {
+    /// Where the submarine ends up after move m from position p
+    fn step(&self, p: Position, m: Move) -> Position;
+}
+
+/// Any function from a position and move to a new position is a movement.
+impl<F: Fn(Position, Move) -> Position> Movement for F {
+    fn step(&self, p: Position, m: Move) -> Position {
+        self(p, m)
+    }
+}
+
+/// Up and down change the depth directly.
+pub struct Plain;
+
+impl Movement for Plain {
+    fn step(&self, mut p: Position, m: Move) -> Position {
+        match m {
+            Move::Forward(i) => p.horiz += i,
+            Move::Back(i) => p.horiz -= i,
+            Move::Down(i) => p.depth += i,
+            Move::Up(i) => p.depth -= i,
+        }
+        p
+    }
+}
+
+/// Up and down change the aim, and moving changes the depth by the aim.
+pub struct Aim;
+
+impl Movement for Aim {
+    fn step(&self, mut p: Position, m: Move) -> Position {
+        match m {
+            Move::Forward(i) => {
+                p.horiz += i;
+                p.depth += p.aim * i
+            }
+            Move::Back(i) => {
+                p.horiz -= i;
+                p.depth -= p.aim * i
+            }
+            Move::Down(i) => p.aim += i,
+            Move::Up(i) => p.aim -= i,
+        }
+        p
+    }
+}
+
+/// Every position the submarine is at while following the updates, starting
+/// from 0,0 and ending at the final position.
+pub fn trajectory(updates: &[Update], m: &impl Movement) -> Vec<Position> {
+    let mut path = vec![Position::default()];
+    for &u in updates {
+        let next = match u {
+            Update::Move(mv) => m.step(*path.last().unwrap(), mv),
+            Update::Reset => Position::default(),
+        };
+        path.push(next);
+    }
+    path
+}
+
+/// Where following the updates ends up, starting from 0,0
+pub fn follow(updates: &[Update], m: &impl Movement) -> Position {
+    *trajectory(updates, m).last().unwrap()
+}
+
+/// Write a trajectory as CSV, one row per position.
+pub fn to_csv(path: &[Position]) -> String {
+    let mut csv = String::from("step,horiz,depth,aim\n");
+    for (i, p) in path.iter().enumerate() {
+        csv.push_str(&format!("{},{},{},{}\n", i, p.horiz, p.depth, p.aim));
+    }
+    csv
+}
+
+/// Draw a trajectory as an SVG depth profile, with the horizontal position
+/// going right and the depth going down.
+pub fn to_svg(path: &[Position]) -> String {
+    let horiz = path.iter().map(|p| p.horiz);
+    let depth = path.iter().map(|p| p.depth);
+    let (left, right) = (horiz.clone().min().unwrap_or(0), horiz.max().unwrap_or(0));
+    let (top, bottom) = (depth.clone().min().unwrap_or(0), depth.max().unwrap_or(0));
+
+    let points = path
+        .iter()
+        .map(|p| format!("{},{}", p.horiz, p.depth))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // a straight course still needs some room to be drawn in, and the stroke
+    // can't scale with the view or long courses would be drawn as a smear
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n\
+         <polyline fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\" points=\"{}\"/>\n\
+         </svg>\n",
+        left,
+        top,
+        (right - left).max(1),
+        (bottom - top).max(1),
+        points
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    /// The sample input from the website
+    #[test]
+    fn test_case() {
+        fixtures::check(2, parse, part1, part2);
+    }
+
+    #[test]
+    fn bad_command() {
+        let e = parse("forward 5\nsideways 3").unwrap_err();
+        assert_eq!(Some(2), e.line);
+        assert_eq!("sideways 3", e.token);
+
+        let e = parse("forward 5\ndown five").unwrap_err();
+        assert_eq!(
+            "line 2, column 6: expected a whole number, found `five`",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn extended_commands() {
+        let updates =
+            parse("# a dive\nforward 5\ndown 3 # steeper\n\nback 2\nreset\nup 1").unwrap();
+        assert_eq!(
+            vec![
+                Update::Move(Move::Forward(5)),
+                Update::Move(Move::Down(3)),
+                Update::Move(Move::Back(2)),
+                Update::Reset,
+                Update::Move(Move::Up(1))
+            ],
+            updates
+        );
+
+        let path = trajectory(&updates, &Aim);
+        assert_eq!(6, path.len());
+        assert_eq!(
+            Position {
+                horiz: 3,
+                depth: -6,
+                aim: 3
+            },
+            path[3]
+        );
+        assert_eq!(
+            Position {
+                horiz: 0,
+                depth: 0,
+                aim: -1
+            },
+            path[5]
+        );
+    }
+
+    #[test]
+    fn custom_movement() {
+        // a submarine that only ever goes twice as far forward
+        let doubled = |mut p: Position, m: Move| {
+            if let Move::Forward(i) = m {
+                p.horiz += 2 * i
+            }
+            p
+        };
+        let updates = parse(fixtures::input(2, 1)).unwrap();
+
+        assert_eq!(30, follow(&updates, &doubled).horiz);
+    }
+
+    #[test]
+    fn export() {
+        let updates = parse(fixtures::input(2, 1)).unwrap();
+        let path = trajectory(&updates, &Aim);
+
+        let csv = to_csv(&path);
+        assert_eq!(Some("step,horiz,depth,aim"), csv.lines().next());
+        assert_eq!(Some("6,15,60,10"), csv.lines().last());
+        assert_eq!(path.len() + 1, csv.lines().count());
+
+        let svg = to_svg(&path);
+        assert!(svg.contains("viewBox=\"0 0 15 60\""));
+        assert!(svg.contains("points=\"0,0 5,0 5,0 13,40 13,40 13,40 15,60\""));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(2, day02::parse, day02::part1, day02::part2);
+}
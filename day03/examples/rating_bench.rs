@@ -0,0 +1,69 @@
+//! Compare the rating filter with the trie on a large random report.
+//!
+//! cargo run --release -p day03 --example rating_bench [LINES] [WIDTH]
+
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use day03::{rating, Bits, Rating, Trie};
+
+const RUNS: usize = 5;
+
+/// The median time of RUNS runs of f.
+fn median<T>(mut f: impl FnMut() -> T) -> Duration {
+    let mut samples = (0..RUNS)
+        .map(|_| {
+            let t = Instant::now();
+            black_box(f());
+            t.elapsed()
+        })
+        .collect::<Vec<_>>();
+    samples.sort_unstable();
+    samples[RUNS / 2]
+}
+
+/// A report of random lines, from a xorshift so every run gets the same one.
+fn report(lines: usize, width: usize) -> Vec<Bits> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..lines)
+        .map(|_| {
+            let n = next();
+            Bits::from_fn(width, |i| n >> (i % 64) & 1 == 1)
+        })
+        .collect()
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let arg = |i: usize, default| args.get(i).map_or(default, |a| a.parse().unwrap());
+    let (lines, width) = (arg(0, 1_000_000), arg(1, 20));
+
+    let report = report(lines, width);
+    println!("{} lines of {} bits", lines, width);
+
+    let filter = median(|| {
+        (
+            rating(&report, Rating::Oxygen),
+            rating(&report, Rating::Co2),
+        )
+    });
+    println!("filter:        {:?}", filter);
+
+    let build = median(|| Trie::new(&report));
+    let trie = Trie::new(&report).expect("a report at most TRIE_WIDTH bits wide");
+    let query = median(|| (trie.rating(Rating::Oxygen), trie.rating(Rating::Co2)));
+    println!("trie build:    {:?}", build);
+    println!("trie ratings:  {:?}", query);
+
+    for r in [Rating::Oxygen, Rating::Co2] {
+        assert_eq!(rating(&report, r), trie.rating(r), "{:?} differs", r);
+    }
+}
@@ -0,0 +1,399 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use aoc_common::parse::{self, ParseError};
+
+const WORD: usize = u64::BITS as usize;
+
+/// The diagnostic report, bit strings that are all the same width.
+pub type Input = Vec<Bits>;
+
+/// Ensure every line of input is a binary string of the same width.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let width = s.lines().next().map_or(0, |l| l.len());
+
+    parse::lines(s, |l| {
+        if let Some((i, c)) = l.chars().enumerate().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(ParseError::new(c, "`0` or `1`").at_column(i + 1));
+        }
+        if l.len() != width {
+            return Err(ParseError::new(l, format!("{} bits", width)));
+        }
+        let chars = l.as_bytes();
+        Ok(Bits::from_fn(width, |i| chars[i] == b'1'))
+    })
+}
+
+/// Return the power consumption (gamma rate * epsilon rate)
+pub fn part1(report: &Input) -> Result<u128, ParseError> {
+    let gamma = gamma(report)?;
+    let epsilon = gamma.flipped();
+
+    Ok(value(&gamma)? * value(&epsilon)?)
+}
+
+/// Return the life support rating (oxygen * co2)
+pub fn part2(report: &Input) -> Result<u128, ParseError> {
+    let (oxygen, co2) = match Trie::new(report) {
+        Some(trie) => (trie.rating(Rating::Oxygen)?, trie.rating(Rating::Co2)?),
+        None => (
+            rating(report, Rating::Oxygen)?,
+            rating(report, Rating::Co2)?,
+        ),
+    };
+
+    Ok(value(&oxygen)? * value(&co2)?)
+}
+
+/// A bit string of any width, packed into u64s. Bit 0 is the leftmost.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bits {
+    words: Vec<u64>,
+    width: usize,
+}
+
+impl Bits {
+    /// Make a bit string where bit i is f(i).
+    pub fn from_fn(width: usize, f: impl Fn(usize) -> bool) -> Bits {
+        let mut b = Bits {
+            words: vec![0; width.div_ceil(WORD)],
+            width,
+        };
+        for i in (0..width).filter(|&i| f(i)) {
+            b.words[i / WORD] |= 1 << (i % WORD);
+        }
+        b
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get bit i. Panics if i is past the width.
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.width, "bit {} of a {} bit string", i, self.width);
+        self.words[i / WORD] >> (i % WORD) & 1 == 1
+    }
+
+    /// Every bit flipped
+    pub fn flipped(&self) -> Bits {
+        Bits::from_fn(self.width, |i| !self.get(i))
+    }
+
+    /// The number the bits spell out, or None if it doesn't fit in a u64.
+    pub fn value(&self) -> Option<u64> {
+        match self.width {
+            0 => Some(0),
+            // bit 0 is the lowest bit of the word but the highest of the value
+            w if w <= WORD => Some(self.words[0].reverse_bits() >> (WORD - w)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.width {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of a rate, or an error if it is too wide to be an answer.
+fn value(b: &Bits) -> Result<u128, ParseError> {
+    b.value()
+        .map(u128::from)
+        .ok_or_else(|| ParseError::new(b, "a report at most 64 bits wide"))
+}
+
+/// Which bit wins when a column has as many 0s as 1s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    One,
+    Zero,
+    /// There is no winner
+    Fail,
+}
+
+/// How many of each bit are in one column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Count {
+    pub zeros: usize,
+    pub ones: usize,
+}
+
+impl Count {
+    /// Count column i of every bit string.
+    pub fn of<'a>(report: impl IntoIterator<Item = &'a Bits>, i: usize) -> Count {
+        let (mut zeros, mut ones) = (0, 0);
+        for b in report {
+            if b.get(i) {
+                ones += 1
+            } else {
+                zeros += 1
+            }
+        }
+        Count { zeros, ones }
+    }
+
+    /// The most common bit, or None on a tie with Tie::Fail
+    pub fn most_common(&self, tie: Tie) -> Option<bool> {
+        match (self.ones.cmp(&self.zeros), tie) {
+            (Ordering::Equal, Tie::One) => Some(true),
+            (Ordering::Equal, Tie::Zero) => Some(false),
+            (Ordering::Equal, Tie::Fail) => None,
+            (o, _) => Some(o.is_gt()),
+        }
+    }
+
+    /// The least common bit, or None on a tie with Tie::Fail
+    pub fn least_common(&self, tie: Tie) -> Option<bool> {
+        // flip the counts, so the least common is the most common
+        let flipped = Count {
+            zeros: self.ones,
+            ones: self.zeros,
+        };
+        flipped.most_common(tie)
+    }
+}
+
+/// Count every column of the report.
+pub fn columns(report: &[Bits]) -> Vec<Count> {
+    let width = report.first().map_or(0, Bits::width);
+    (0..width).map(|i| Count::of(report, i)).collect()
+}
+
+/// The gamma rate: the most common bit of every column.
+fn gamma(report: &[Bits]) -> Result<Bits, ParseError> {
+    let counts = columns(report);
+    let bits = counts
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            c.most_common(Tie::Fail).ok_or_else(|| {
+                ParseError::new(
+                    format!("as many 0s as 1s in column {}", i + 1),
+                    "a most common bit in every column",
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Bits::from_fn(bits.len(), |i| bits[i]))
+}
+
+/// The ratings on the life support report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rating {
+    /// Keep the most common bit, 1 on ties
+    Oxygen,
+    /// Keep the least common bit, 0 on ties
+    Co2,
+}
+
+impl Rating {
+    /// The bit to keep in a column with these counts
+    fn keep(&self, c: &Count) -> bool {
+        // When every line agrees, keeping the least common bit would keep
+        // nothing, so keep them all
+        if c.ones == 0 || c.zeros == 0 {
+            return c.ones > 0;
+        }
+
+        match self {
+            Rating::Oxygen => c.most_common(Tie::One),
+            Rating::Co2 => c.least_common(Tie::Zero),
+        }
+        .unwrap()
+    }
+}
+
+/// Filter the report column by column down to one bit string for the rating.
+/// This rescans what's left for every column, Trie::rating is faster for
+/// reports that fit one.
+pub fn rating(report: &[Bits], r: Rating) -> Result<Bits, ParseError> {
+    let mut results = report.iter().collect::<Vec<_>>();
+    let width = report.first().map_or(0, Bits::width);
+
+    for i in 0..width {
+        if results.len() <= 1 {
+            break;
+        }
+
+        let keep = r.keep(&Count::of(results.iter().copied(), i));
+        results.retain(|b| b.get(i) == keep);
+    }
+
+    // Anything left after every column is the same bit string
+    results
+        .first()
+        .map(|&b| b.clone())
+        .ok_or_else(|| ParseError::whole("the report has no lines"))
+}
+
+/// The widest report Trie counts prefixes for, which takes 2^(width + 1)
+/// counts (16MB at 20 bits).
+pub const TRIE_WIDTH: usize = 20;
+
+/// How many bit strings in a report start with each prefix.
+#[derive(Debug)]
+pub struct Trie {
+    /// levels[d][p] is how many bit strings start with the d bits of p
+    levels: Vec<Vec<usize>>,
+    width: usize,
+}
+
+impl Trie {
+    /// Count the prefixes of a report in O(lines + 2^width), or None if it's
+    /// wider than TRIE_WIDTH.
+    pub fn new(report: &[Bits]) -> Option<Trie> {
+        let width = report.first().map_or(0, Bits::width);
+        if width > TRIE_WIDTH {
+            return None;
+        }
+
+        // Count every whole bit string, then each level up adds up the two
+        // prefixes below it
+        let mut leaves = vec![0; 1 << width];
+        for b in report {
+            leaves[b.value()? as usize] += 1;
+        }
+
+        let mut levels = vec![leaves];
+        for _ in 0..width {
+            let below = levels.last().unwrap();
+            let level = below.chunks(2).map(|c| c[0] + c[1]).collect();
+            levels.push(level);
+        }
+        levels.reverse();
+
+        Some(Trie { levels, width })
+    }
+
+    /// Find the bit string for the rating in O(width), by following the
+    /// prefixes that the filter would keep.
+    pub fn rating(&self, r: Rating) -> Result<Bits, ParseError> {
+        if self.levels[0][0] == 0 {
+            return Err(ParseError::whole("the report has no lines"));
+        }
+
+        let mut prefix = 0;
+        for below in &self.levels[1..] {
+            let c = Count {
+                zeros: below[2 * prefix],
+                ones: below[2 * prefix + 1],
+            };
+            prefix = 2 * prefix + r.keep(&c) as usize;
+        }
+
+        let width = self.width;
+        Ok(Bits::from_fn(width, |i| prefix >> (width - 1 - i) & 1 == 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn website_sample() {
+        let report = parse(fixtures::input(3, 1)).unwrap();
+
+        let gamma = gamma(&report).unwrap();
+        let epsilon = gamma.flipped();
+
+        assert_eq!(Some(22), gamma.value());
+        assert_eq!(Some(9), epsilon.value());
+    }
+
+    #[test]
+    fn oxygen() {
+        let report = parse(fixtures::input(3, 1)).unwrap();
+        assert_eq!(Some(23), rating(&report, Rating::Oxygen).unwrap().value());
+    }
+
+    #[test]
+    fn co2() {
+        let report = parse(fixtures::input(3, 1)).unwrap();
+        assert_eq!(Some(10), rating(&report, Rating::Co2).unwrap().value());
+    }
+
+    #[test]
+    fn website_answer() {
+        fixtures::check(3, parse, part1, part2);
+    }
+
+    #[test]
+    fn ties() {
+        let c = Count { zeros: 2, ones: 2 };
+        assert_eq!(Some(true), c.most_common(Tie::One));
+        assert_eq!(Some(false), c.least_common(Tie::Zero));
+        assert_eq!(None, c.most_common(Tie::Fail));
+
+        let e = part1(&parse("01\n10").unwrap()).unwrap_err();
+        assert_eq!("as many 0s as 1s in column 1", e.token);
+    }
+
+    #[test]
+    fn wide() {
+        let one = format!("1{}", "0".repeat(99));
+        let report = parse(&format!("{}\n{}\n{}", one, one, "1".repeat(100))).unwrap();
+
+        assert_eq!(100, report[0].width());
+        assert_eq!(one, gamma(&report).unwrap().to_string());
+        assert_eq!(
+            "a report at most 64 bits wide",
+            part1(&report).unwrap_err().expected
+        );
+
+        // 64 bits still fits
+        let report = parse(&format!("{}\n{}", "1".repeat(64), "1".repeat(64))).unwrap();
+        assert_eq!(
+            Some(u64::MAX),
+            rating(&report, Rating::Oxygen).unwrap().value()
+        );
+    }
+
+    #[test]
+    fn bad_bit() {
+        let e = parse("0101\n0121").unwrap_err();
+        assert_eq!(
+            "line 2, column 3: expected `0` or `1`, found `2`",
+            e.to_string()
+        );
+
+        let e = parse("01\n0é").unwrap_err();
+        assert_eq!(
+            "line 2, column 2: expected `0` or `1`, found `é`",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn trie_matches_filter() {
+        let report = parse(fixtures::input(3, 1)).unwrap();
+        let trie = Trie::new(&report).unwrap();
+
+        for r in [Rating::Oxygen, Rating::Co2] {
+            assert_eq!(rating(&report, r), trie.rating(r));
+        }
+
+        // every line agreeing on the last bit, and duplicates
+        let report = parse("0010\n0110\n0110\n1110").unwrap();
+        let trie = Trie::new(&report).unwrap();
+        for r in [Rating::Oxygen, Rating::Co2] {
+            assert_eq!(rating(&report, r), trie.rating(r));
+        }
+        assert_eq!("0110", trie.rating(Rating::Oxygen).unwrap().to_string());
+
+        assert!(Trie::new(&[]).unwrap().rating(Rating::Oxygen).is_err());
+
+        // too wide for a trie, so part2 filters instead
+        let wide = format!("1{}", "0".repeat(TRIE_WIDTH));
+        let report = parse(&format!("{}\n{}", wide, wide)).unwrap();
+        assert!(Trie::new(&report).is_none());
+        assert_eq!(Ok(1 << (2 * TRIE_WIDTH)), part2(&report));
+    }
+}
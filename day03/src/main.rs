@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(3, day03::parse, day03::part1, day03::part2);
+}
@@ -0,0 +1,472 @@
+use std::collections::HashMap;
+
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::{self, comma_separated, ParseError};
+
+/// The numbers that will be called, in order, and every bingo board.
+#[derive(Debug)]
+pub struct Input {
+    nums: Vec<i32>,
+    boards: Vec<Board>,
+    /// Every cell each number is in, as (board, cell), in board order
+    index: HashMap<i32, Vec<(usize, Point)>>,
+}
+
+/// Parse an input string into the numbers that will be marked and all boards
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut iter = s.split("\n\n");
+
+    // First input is the drawn numbers
+    let nums = comma_separated(iter.next().unwrap_or_default())?;
+
+    // Track which line each board starts on so errors point at the right place
+    let mut line = 2;
+    let boards = iter
+        .map(|b| {
+            let board = Board::new(b).map_err(|e| e.shifted(line));
+            line += b.lines().count() + 1;
+            board
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut index = HashMap::<_, Vec<_>>::new();
+    for (i, b) in boards.iter().enumerate() {
+        for p in b.numbers.points() {
+            index.entry(b.numbers[p]).or_default().push((i, p));
+        }
+    }
+
+    Ok(Input {
+        nums,
+        boards,
+        index,
+    })
+}
+
+/// Return the final score of the first winning board
+pub fn part1(input: &Input) -> Result<i32, ParseError> {
+    let wins = play(input, &standard());
+    wins.first()
+        .map(|w| w.score)
+        .ok_or_else(|| no_winner(input))
+}
+
+/// Return the final score of the last winning board
+pub fn part2(input: &Input) -> Result<i32, ParseError> {
+    let wins = play(input, &standard());
+    wins.last().map(|w| w.score).ok_or_else(|| no_winner(input))
+}
+
+/// The error for when every number is called and still nobody has won
+fn no_winner(input: &Input) -> ParseError {
+    ParseError::whole(format!(
+        "no board wins after all {} calls",
+        input.nums.len()
+    ))
+}
+
+/// A board winning the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Which board won, in the order of the input
+    pub board: usize,
+    /// How many numbers had been called when it won
+    pub calls: usize,
+    /// 1 for the boards that won first, 2 for the next ones to win, and so on.
+    /// Boards that win on the same call share a place.
+    pub place: usize,
+    /// The number that won it
+    pub number: i32,
+    /// The winning number times the sum of the unmarked numbers
+    pub score: i32,
+}
+
+/// Call every number and return the boards in the order they win, with the
+/// call they won on. Boards that win on the same call are in the order of the
+/// input, and boards that never win are left out.
+pub fn play(input: &Input, rule: &impl WinRule) -> Vec<Win> {
+    let mut boards = input.boards.clone();
+    let mut wins = Vec::new();
+    let mut place = 0;
+
+    for (calls, n) in input.nums.iter().enumerate() {
+        let won_before = wins.len();
+        let cells = input.index.get(n).map_or(&[][..], Vec::as_slice);
+
+        // Only the boards with n on them can change, and the index has every
+        // cell of a board together so a number on a board twice is all
+        // marked before checking for a win
+        for cells in cells.chunk_by(|a, b| a.0 == b.0) {
+            let i = cells[0].0;
+            let b = &mut boards[i];
+            if b.won {
+                continue;
+            }
+
+            let marked = cells
+                .iter()
+                .filter(|&&(_, p)| b.mark(p))
+                .map(|&(_, p)| p)
+                .collect::<Vec<_>>();
+            if marked.iter().any(|&p| rule.wins(&b.marks, p)) {
+                b.won = true;
+                wins.push(Win {
+                    board: i,
+                    calls: calls + 1,
+                    place: place + 1,
+                    number: *n,
+                    score: n * b.unmarked,
+                });
+            }
+        }
+
+        if wins.len() > won_before {
+            place += 1;
+        }
+    }
+
+    wins
+}
+
+/// Split wins from play into the groups of boards that won on the same call.
+pub fn simultaneous(wins: &[Win]) -> impl Iterator<Item = &[Win]> {
+    wins.chunk_by(|a, b| a.place == b.place)
+}
+
+/// The marked cells of a board, along with running counts of them so that
+/// rules don't have to look at the whole board.
+#[derive(Debug, Clone)]
+pub struct Marks {
+    marked: Grid<bool>,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    /// Marks on the diagonal from the top left, and from the top right
+    diagonals: [usize; 2],
+    count: usize,
+}
+
+impl Marks {
+    fn new(width: usize, height: usize) -> Marks {
+        Marks {
+            marked: Grid::new(width, height, false),
+            rows: vec![0; height],
+            columns: vec![0; width],
+            diagonals: [0; 2],
+            count: 0,
+        }
+    }
+
+    /// Mark a cell, returning false if it already was.
+    fn mark(&mut self, (x, y): Point) -> bool {
+        if self.marked[(x, y)] {
+            return false;
+        }
+
+        self.marked[(x, y)] = true;
+        self.rows[y] += 1;
+        self.columns[x] += 1;
+        if x == y {
+            self.diagonals[0] += 1;
+        }
+        if x + y + 1 == self.marked.width() {
+            self.diagonals[1] += 1;
+        }
+        self.count += 1;
+        true
+    }
+
+    pub fn marked(&self) -> &Grid<bool> {
+        &self.marked
+    }
+
+    /// Whether row y is fully marked
+    pub fn full_row(&self, y: usize) -> bool {
+        self.rows[y] == self.marked.width()
+    }
+
+    /// Whether column x is fully marked
+    pub fn full_column(&self, x: usize) -> bool {
+        self.columns[x] == self.marked.height()
+    }
+
+    /// Whether the diagonal from the top left (0) or top right (1) is fully
+    /// marked. Only square boards have diagonals.
+    pub fn full_diagonal(&self, d: usize) -> bool {
+        self.marked.width() == self.marked.height() && self.diagonals[d] == self.marked.width()
+    }
+
+    /// How many cells are marked
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// A way for a board to win. Boards only ever gain marks, so a board can only
+/// start winning because of the cell that was just marked, which is given as
+/// last so rules can just check around it.
+pub trait WinRule {
+    fn wins(&self, marks: &Marks, last: Point) -> bool;
+}
+
+/// Any function from the marked cells to whether they win is a rule.
+impl<F: Fn(&Grid<bool>) -> bool> WinRule for F {
+    fn wins(&self, marks: &Marks, _: Point) -> bool {
+        self(marks.marked())
+    }
+}
+
+/// Any row is fully marked.
+pub struct Rows;
+
+impl WinRule for Rows {
+    fn wins(&self, marks: &Marks, (_, y): Point) -> bool {
+        marks.full_row(y)
+    }
+}
+
+/// Any column is fully marked.
+pub struct Columns;
+
+impl WinRule for Columns {
+    fn wins(&self, marks: &Marks, (x, _): Point) -> bool {
+        marks.full_column(x)
+    }
+}
+
+/// Either diagonal is fully marked. Only square boards have diagonals.
+pub struct Diagonals;
+
+impl WinRule for Diagonals {
+    fn wins(&self, marks: &Marks, _: Point) -> bool {
+        marks.full_diagonal(0) || marks.full_diagonal(1)
+    }
+}
+
+/// Every number on the board is marked.
+pub struct FullCard;
+
+impl WinRule for FullCard {
+    fn wins(&self, marks: &Marks, _: Point) -> bool {
+        marks.count() == marks.marked().width() * marks.marked().height()
+    }
+}
+
+/// All four corners are marked.
+pub struct Corners;
+
+impl WinRule for Corners {
+    fn wins(&self, marks: &Marks, _: Point) -> bool {
+        let m = marks.marked();
+        let (w, h) = (m.width(), m.height());
+        [(0, 0), (w - 1, 0), (0, h - 1), (w - 1, h - 1)]
+            .iter()
+            .all(|&p| m[p])
+    }
+}
+
+/// Wins when any of its rules do.
+pub struct AnyOf(pub Vec<Box<dyn WinRule>>);
+
+impl WinRule for AnyOf {
+    fn wins(&self, marks: &Marks, last: Point) -> bool {
+        self.0.iter().any(|r| r.wins(marks, last))
+    }
+}
+
+/// The rules from the puzzle: a full row or column.
+pub fn standard() -> AnyOf {
+    AnyOf(vec![Box::new(Rows), Box::new(Columns)])
+}
+
+#[derive(Debug, Clone)]
+/// Bingo board
+struct Board {
+    numbers: Grid<i32>,
+    marks: Marks,
+    /// The sum of the unmarked numbers
+    unmarked: i32,
+    won: bool,
+}
+
+impl Board {
+    /// Given a string of a board, creates a new board. Boards can be any
+    /// size, as long as every row is the same length.
+    fn new(s: &str) -> Result<Board, ParseError> {
+        let rows = parse::lines(s, |l| {
+            l.split_whitespace()
+                .map(|i| parse::token(i, "a number"))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        let width = rows.first().map_or(0, |r| r.len());
+        if width == 0 || rows.iter().any(|r| r.len() != width) {
+            return Err(ParseError::new(
+                s.trim(),
+                "a board of rows that all have the same amount of numbers",
+            ));
+        }
+
+        let numbers = Grid::from_rows(rows);
+
+        Ok(Board {
+            marks: Marks::new(numbers.width(), numbers.height()),
+            unmarked: numbers.iter().sum(),
+            numbers,
+            won: false,
+        })
+    }
+
+    /// Mark the cell at p, returning false if it already was.
+    fn mark(&mut self, p: Point) -> bool {
+        let newly = self.marks.mark(p);
+        if newly {
+            self.unmarked -= self.numbers[p];
+        }
+        newly
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const SIMPLE_BOARD: &str =
+        "1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+
+    #[test]
+    fn web_test() {
+        fixtures::check(4, parse, part1, part2);
+    }
+
+    #[test]
+    fn finishing_order() {
+        let input = parse(fixtures::input(4, 1)).unwrap();
+        let wins = play(&input, &standard());
+
+        assert_eq!(
+            vec![2, 0, 1],
+            wins.iter().map(|w| w.board).collect::<Vec<_>>()
+        );
+        assert_eq!(24, wins[0].number);
+        assert_eq!(12, wins[0].calls);
+        assert_eq!(13, wins[2].number);
+    }
+
+    /// Mark n on b, and return whether the rule wins because of it
+    fn call(b: &mut Board, n: i32, rule: &impl WinRule) -> bool {
+        let p = b.numbers.points().find(|&p| b.numbers[p] == n).unwrap();
+        b.mark(p);
+        rule.wins(&b.marks, p)
+    }
+
+    #[test]
+    /// Create a test board and ensure the index holds what's expected
+    fn create_board() {
+        let input = parse(&format!("1\n\n{}", SIMPLE_BOARD)).unwrap();
+
+        assert_eq!(&Grid::new(5, 5, false), input.boards[0].marks.marked());
+        assert_eq!(325, input.boards[0].unmarked);
+        for i in 0..25 {
+            assert_eq!(vec![(0, (i % 5, i / 5))], input.index[&(i as i32 + 1)]);
+        }
+    }
+
+    #[test]
+    /// Ensure correct number is marked
+    fn mark() {
+        let mut b = Board::new(SIMPLE_BOARD).unwrap();
+
+        assert!(b.mark((4, 0)));
+        assert!(!b.mark((4, 0)));
+
+        let mut ans = Grid::new(5, 5, false);
+        ans[(4, 0)] = true;
+
+        assert_eq!(&ans, b.marks.marked());
+        assert_eq!(320, b.unmarked);
+    }
+
+    #[test]
+    fn win_hori() {
+        let mut b = Board::new(SIMPLE_BOARD).unwrap();
+
+        for i in [3, 8, 13, 18] {
+            assert!(!call(&mut b, i, &standard()));
+        }
+
+        assert!(call(&mut b, 23, &standard()))
+    }
+
+    #[test]
+    fn win_vert() {
+        let mut b = Board::new(SIMPLE_BOARD).unwrap();
+
+        for i in [6, 7, 8, 9] {
+            assert!(!call(&mut b, i, &standard()));
+        }
+
+        assert!(call(&mut b, 10, &standard()))
+    }
+
+    #[test]
+    fn other_rules() {
+        let mut b = Board::new("1 2 3\n4 5 6").unwrap();
+        for i in [1, 3] {
+            call(&mut b, i, &Corners);
+        }
+        assert!(!call(&mut b, 4, &Corners));
+        assert!(!call(&mut b, 5, &Diagonals));
+        assert!(call(&mut b, 6, &Corners));
+        assert!(!call(&mut b, 2, &Diagonals));
+        assert!(FullCard.wins(&b.marks, (0, 0)));
+
+        let mut b = Board::new("1 2\n3 4").unwrap();
+        call(&mut b, 2, &Diagonals);
+        assert!(call(&mut b, 3, &Diagonals));
+        assert!(!standard().wins(&b.marks, (0, 1)));
+
+        // anything that looks at the marks can be a rule
+        let two_marked = |m: &Grid<bool>| m.iter().filter(|&&x| x).count() >= 2;
+        assert!(two_marked.wins(&b.marks, (0, 1)));
+    }
+
+    #[test]
+    fn repeated_number() {
+        // both 5s are marked by the first call, which finishes the first column
+        let input = parse("5,1\n\n5 1\n5 2").unwrap();
+        assert_eq!(Ok(5 * (1 + 2)), part1(&input));
+    }
+
+    #[test]
+    fn ragged_board() {
+        let e = parse("1,2\n\n1 2\n3").unwrap_err();
+        assert_eq!(Some(3), e.line);
+    }
+
+    #[test]
+    fn ties() {
+        // the second and third boards both win on 3, and the last never wins
+        let input = parse("1,2,3,4\n\n1 2\n10 11\n\n3 12\n2 13\n\n3 2\n14 15\n\n8 7\n6 5").unwrap();
+        let wins = play(&input, &standard());
+
+        assert_eq!(
+            vec![(0, 1), (1, 2), (2, 2)],
+            wins.iter().map(|w| (w.board, w.place)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 2],
+            simultaneous(&wins).map(|r| r.len()).collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(3 * 29), part2(&input));
+    }
+
+    #[test]
+    fn no_winner() {
+        let input = parse("1,2\n\n1 3\n4 5").unwrap();
+        assert_eq!(
+            "no board wins after all 2 calls",
+            part1(&input).unwrap_err().to_string()
+        );
+    }
+}
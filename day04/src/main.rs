@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(4, day04::parse, day04::part1, day04::part2);
+}
@@ -0,0 +1,570 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::grid::{self, Point};
+use aoc_common::parse::{self, ParseError};
+
+/// The most cells Backend::choose will put in a dense grid, 64MB of counts.
+const DENSE_LIMIT: usize = 1 << 24;
+/// The most points Backend::choose will draw into a sparse map.
+const SPARSE_LIMIT: usize = 1 << 22;
+
+/// Every line of hydrothermal vents.
+pub type Input = Vec<Line>;
+
+/// Takes puzzle input and return vector of Line
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    parse::lines(s, Line::new)
+}
+
+/// Return the number of points where at least two horizontal or vertical
+/// lines overlap
+pub fn part1(lines: &Input) -> usize {
+    overlaps(lines, false, Backend::choose(lines))
+}
+
+/// Return the number of points where at least two lines overlap, counting
+/// diagonal lines too
+pub fn part2(lines: &Input) -> usize {
+    overlaps(lines, true, Backend::choose(lines))
+}
+
+/// How to store the number of lines on each point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// A grid covering every line, best when the lines are packed together
+    Dense,
+    /// Only the points that have lines, best when they're spread out
+    Sparse,
+    /// No points at all, work out the overlaps of each pair of lines instead.
+    /// Best when the lines are too long to draw.
+    Analytic,
+}
+
+impl Backend {
+    /// Dense, unless the grid covering the lines would be too big. Then
+    /// Sparse if there aren't too many points on the lines to draw, and
+    /// Analytic if there are.
+    pub fn choose(lines: &[Line]) -> Backend {
+        let ((x1, y1), (x2, y2)) = bounds(lines);
+        let points = lines
+            .iter()
+            .map(|l| Segment::new(l).len as usize + 1)
+            .fold(0, usize::saturating_add);
+
+        match (x2 - x1).checked_mul(y2 - y1) {
+            Some(cells) if cells <= DENSE_LIMIT => Backend::Dense,
+            _ if points <= SPARSE_LIMIT => Backend::Sparse,
+            _ => Backend::Analytic,
+        }
+    }
+}
+
+/// Return the number of points where at least two lines overlap, skipping
+/// diagonal lines unless asked for
+pub fn overlaps(lines: &[Line], diagonals: bool, backend: Backend) -> usize {
+    match backend {
+        Backend::Dense => {
+            let mut g = Dense::new(lines);
+            g.draw_lines(lines, diagonals);
+            g.two_line_overlap()
+        }
+        Backend::Sparse => {
+            let mut g = Sparse::default();
+            g.draw_lines(lines, diagonals);
+            g.two_line_overlap()
+        }
+        Backend::Analytic => analytic_overlap(lines, diagonals),
+    }
+}
+
+/// The smallest area holding every line, as the top left point and the
+/// bottom right point (exclusive).
+fn bounds(lines: &[Line]) -> (Point, Point) {
+    let xs = lines.iter().flat_map(|l| [l.x1, l.x2]);
+    let ys = lines.iter().flat_map(|l| [l.y1, l.y2]);
+
+    (
+        (xs.clone().min().unwrap_or(0), ys.clone().min().unwrap_or(0)),
+        (xs.max().map_or(0, |x| x + 1), ys.max().map_or(0, |y| y + 1)),
+    )
+}
+
+/// Somewhere to count how many lines are on each point.
+trait Counts {
+    /// Add a line to the count at p
+    fn add(&mut self, p: Point);
+
+    /// Get the number of points that have two or more lines overlapping
+    fn two_line_overlap(&self) -> usize;
+
+    // Draw all lines, skipping diagonal ones unless asked for
+    fn draw_lines(&mut self, lines: &[Line], diagonals: bool) {
+        for line in lines {
+            self.draw_line(line, diagonals)
+        }
+    }
+
+    // Draw a line, stepping between the points with whole number coordinates
+    // on it just like Segment does, so every backend agrees on sloped lines
+    fn draw_line(&mut self, l: &Line, diagonals: bool) {
+        if !diagonals && l.x1 != l.x2 && l.y1 != l.y2 {
+            return;
+        }
+
+        let s = Segment::new(l);
+        for k in 0..=s.len {
+            let (x, y) = (s.start.0 + k * s.step.0, s.start.1 + k * s.step.1);
+            self.add((x as usize, y as usize));
+        }
+    }
+}
+
+/// A grid just big enough for the lines it was made for.
+struct Dense {
+    /// The point that grid[(0, 0)] is
+    origin: Point,
+    grid: grid::Grid<u32>,
+}
+
+impl Dense {
+    // New blank grid covering every line
+    fn new(lines: &[Line]) -> Dense {
+        let ((x1, y1), (x2, y2)) = bounds(lines);
+        Dense {
+            origin: (x1, y1),
+            grid: grid::Grid::new(x2 - x1, y2 - y1, 0),
+        }
+    }
+}
+
+impl Counts for Dense {
+    fn add(&mut self, (x, y): Point) {
+        self.grid[(x - self.origin.0, y - self.origin.1)] += 1
+    }
+
+    fn two_line_overlap(&self) -> usize {
+        self.grid.iter().filter(|&x| x > &1).count()
+    }
+}
+
+/// Counts for only the points that have lines on them.
+#[derive(Default)]
+struct Sparse {
+    counts: HashMap<Point, u32>,
+}
+
+impl Counts for Sparse {
+    fn add(&mut self, p: Point) {
+        *self.counts.entry(p).or_default() += 1
+    }
+
+    fn two_line_overlap(&self) -> usize {
+        self.counts.values().filter(|&x| x > &1).count()
+    }
+}
+
+/// A point or direction with room for the math on them. Coordinates are u32,
+/// so products of two of them need more than an i64.
+type Vector = (i128, i128);
+
+fn cross(a: Vector, b: Vector) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn dot(a: Vector, b: Vector) -> i128 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// A line as the points start + k * step for k in 0..=len. Lines of any slope
+/// work, the points are the ones with whole number coordinates.
+struct Segment {
+    start: Vector,
+    step: Vector,
+    len: i128,
+}
+
+impl Segment {
+    fn new(l: &Line) -> Segment {
+        let (a, b) = ((l.x1 as i128, l.y1 as i128), (l.x2 as i128, l.y2 as i128));
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+
+        let len = gcd(dx, dy);
+        if len == 0 {
+            // a single point, which is as good as a horizontal line
+            return Segment {
+                start: a,
+                step: (1, 0),
+                len,
+            };
+        }
+
+        // point parallel segments the same way, so they share a step
+        let step = (dx / len, dy / len);
+        if step.0 < 0 || (step.0 == 0 && step.1 < 0) {
+            Segment {
+                start: b,
+                step: (-step.0, -step.1),
+                len,
+            }
+        } else {
+            Segment {
+                start: a,
+                step,
+                len,
+            }
+        }
+    }
+
+    /// The infinite line this segment is a piece of, as its step and which of
+    /// the parallel lines with that step it is
+    fn carrier(&self) -> (Vector, i128) {
+        (self.step, cross(self.step, self.start))
+    }
+
+    /// The point of this segment where it crosses other, if they aren't
+    /// parallel and cross on a whole number point
+    fn crossing(&self, other: &Segment) -> Option<Vector> {
+        let denom = cross(self.step, other.step);
+        if denom == 0 {
+            return None;
+        }
+
+        let diff = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let (a, b) = (cross(diff, other.step), cross(diff, self.step));
+        if a % denom != 0 || b % denom != 0 {
+            return None;
+        }
+
+        let (a, b) = (a / denom, b / denom);
+        ((0..=self.len).contains(&a) && (0..=other.len).contains(&b)).then(|| {
+            (
+                self.start.0 + a * self.step.0,
+                self.start.1 + a * self.step.1,
+            )
+        })
+    }
+}
+
+/// Where a point is along any line with this step: consecutive points on the
+/// line are consecutive positions.
+fn position(step: Vector, p: Vector) -> i128 {
+    dot(p, step).div_euclid(dot(step, step))
+}
+
+/// Given the ranges of positions some segments cover on one line, return the
+/// ranges covered by at least two of them, in order.
+fn covered_twice(ranges: &[(i128, i128)]) -> Vec<(i128, i128)> {
+    let mut events = ranges
+        .iter()
+        .flat_map(|&(start, end)| [(start, 1), (end + 1, -1)])
+        .collect::<Vec<_>>();
+    events.sort_unstable();
+
+    let mut covered = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (pos, change) in events {
+        let before = depth;
+        depth += change;
+        if before < 2 && depth >= 2 {
+            start = pos;
+        } else if before >= 2 && depth < 2 {
+            covered.push((start, pos - 1));
+        }
+    }
+    covered
+}
+
+/// Count the points where at least two lines overlap without drawing any of
+/// them. Lines along the same infinite line overlap in ranges, which are
+/// counted a range at a time, and every other pair of lines crosses on at most
+/// one point.
+fn analytic_overlap(lines: &[Line], diagonals: bool) -> usize {
+    let segments = lines
+        .iter()
+        .filter(|l| diagonals || l.x1 == l.x2 || l.y1 == l.y2)
+        .map(Segment::new)
+        .collect::<Vec<_>>();
+
+    // Ranges covered twice on each infinite line
+    let mut carriers = HashMap::<_, Vec<_>>::new();
+    for s in &segments {
+        let start = position(s.step, s.start);
+        carriers
+            .entry(s.carrier())
+            .or_default()
+            .push((start, start + s.len));
+    }
+    let carriers = carriers
+        .into_iter()
+        .map(|(c, ranges)| (c, covered_twice(&ranges)))
+        .filter(|(_, covered)| !covered.is_empty())
+        .collect::<HashMap<_, _>>();
+
+    let mut total = carriers
+        .values()
+        .flatten()
+        .map(|(start, end)| (end - start + 1) as usize)
+        .sum::<usize>();
+
+    let mut crossings = HashSet::new();
+    for (i, a) in segments.iter().enumerate() {
+        crossings.extend(segments[i + 1..].iter().filter_map(|b| a.crossing(b)));
+    }
+
+    // A crossing has been counted once for every infinite line it is covered
+    // twice on, and should be counted exactly once
+    let steps = carriers
+        .keys()
+        .map(|&(step, _)| step)
+        .collect::<HashSet<_>>();
+    for p in crossings {
+        let counted = steps
+            .iter()
+            .filter(|&&step| {
+                let pos = position(step, p);
+                carriers
+                    .get(&(step, cross(step, p)))
+                    .is_some_and(|covered| {
+                        let i = covered.partition_point(|&(_, end)| end < pos);
+                        covered.get(i).is_some_and(|&(start, _)| start <= pos)
+                    })
+            })
+            .count();
+
+        if counted == 0 {
+            total += 1;
+        } else {
+            total -= counted - 1;
+        }
+    }
+
+    total
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Line {
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+}
+
+impl Line {
+    /// Takes a line of puzzle input like "0,9 -> 5,9" and converts it into a Line
+    fn new(s: &str) -> Result<Line, ParseError> {
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::new(s, "two points like `x1,y1 -> x2,y2`"))?;
+
+        // Coordinates past a u32 would overflow the math on them
+        let coordinate = |c: &str| {
+            parse::token::<u32>(c.trim(), "a coordinate from 0 to 4294967295").map(|c| c as usize)
+        };
+        let point = |p: &str| match p.split_once(',') {
+            Some((x, y)) => Ok((coordinate(x)?, coordinate(y)?)),
+            None => Err(ParseError::new(p, "a point like `x,y`")),
+        };
+
+        let ((x1, y1), (x2, y2)) = (point(start)?, point(end)?);
+
+        Ok(Line { x1, y1, x2, y2 })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const WEB_INPUT: &str = fixtures::input(5, 1);
+
+    const BASIC: &str = "\
+0,0 -> 0,1
+2,2 -> 3,2";
+
+    #[test]
+    fn web_example() {
+        fixtures::check(5, parse, part1, part2);
+    }
+
+    #[test]
+    fn draw_web() {
+        let lines = parse(WEB_INPUT).unwrap();
+        let mut g = Dense::new(&lines);
+        g.draw_lines(&lines, false);
+
+        let ans = [
+            [0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+            [0, 1, 1, 2, 1, 1, 1, 2, 1, 1],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [2, 2, 2, 1, 1, 1, 0, 0, 0, 0],
+        ];
+
+        assert!(ans
+            .iter()
+            .flatten()
+            .zip(g.grid.rows().flat_map(|a| a.iter().take(10)))
+            .all(|(x, y)| x == y));
+    }
+
+    #[test]
+    fn draw_web_diagonal() {
+        let lines = parse(WEB_INPUT).unwrap();
+        let mut g = Dense::new(&lines);
+        g.draw_lines(&lines, true);
+
+        let ans = [
+            [1, 0, 1, 0, 0, 0, 0, 1, 1, 0],
+            [0, 1, 1, 1, 0, 0, 0, 2, 0, 0],
+            [0, 0, 2, 0, 1, 0, 1, 1, 1, 0],
+            [0, 0, 0, 1, 0, 2, 0, 2, 0, 0],
+            [0, 1, 1, 2, 3, 1, 3, 2, 1, 1],
+            [0, 0, 0, 1, 0, 2, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0, 1, 0, 0],
+            [1, 0, 0, 0, 0, 0, 0, 0, 1, 0],
+            [2, 2, 2, 1, 1, 1, 0, 0, 0, 0],
+        ];
+
+        assert!(ans
+            .iter()
+            .flatten()
+            .zip(g.grid.rows().flat_map(|a| a.iter().take(10)))
+            .all(|(x, y)| x == y));
+    }
+
+    #[test]
+    fn backends() {
+        let lines = parse(WEB_INPUT).unwrap();
+        for diagonals in [false, true] {
+            assert_eq!(
+                overlaps(&lines, diagonals, Backend::Dense),
+                overlaps(&lines, diagonals, Backend::Sparse)
+            );
+            assert_eq!(
+                overlaps(&lines, diagonals, Backend::Dense),
+                overlaps(&lines, diagonals, Backend::Analytic)
+            );
+        }
+
+        // far from the origin, and far apart
+        let lines = parse("5000000,7 -> 5000000,9\n4999999,8 -> 5000001,8\n0,0 -> 0,0").unwrap();
+        assert_eq!(Backend::Sparse, Backend::choose(&lines));
+        assert_eq!(1, part1(&lines));
+        assert_eq!(1, overlaps(&lines, false, Backend::Analytic));
+
+        // far apart, and too long to draw
+        let lines = parse("0,0 -> 0,5000000\n9000000,0 -> 0,0").unwrap();
+        assert_eq!(Backend::Analytic, Backend::choose(&lines));
+        assert_eq!(1, part1(&lines));
+
+        // the biggest coordinates, and one past them
+        let max = u32::MAX;
+        let lines = parse(&format!("{0},0 -> {0},1\n{0},1 -> 0,{0}", max)).unwrap();
+        assert_eq!(Backend::Sparse, Backend::choose(&lines));
+        assert_eq!(0, part1(&lines));
+        assert_eq!(1, part2(&lines));
+        assert_eq!(1, overlaps(&lines, true, Backend::Sparse));
+        assert_eq!(1, overlaps(&lines, true, Backend::Analytic));
+        let e = parse(&format!("0,0 -> 0,{}", max as u64 + 1)).unwrap_err();
+        assert_eq!("a coordinate from 0 to 4294967295", e.expected);
+
+        // more overlaps on one point than a u8 can count
+        let lines = parse(&"3,3 -> 3,4\n".repeat(300)).unwrap();
+        assert_eq!(Backend::Dense, Backend::choose(&lines));
+        let mut g = Dense::new(&lines);
+        g.draw_lines(&lines, false);
+        assert_eq!(300, g.grid[(0, 0)]);
+        assert_eq!(2, part1(&lines));
+
+        // lines that aren't at 45 degrees only cover their whole number points
+        let sloped = [
+            "0,0 -> 4,2\n2,2 -> 2,2",
+            "0,0 -> 4,2\n2,1 -> 2,1",
+            "0,0 -> 4,2\n0,2 -> 4,0\n8,0 -> 10,1\n8,1 -> 10,0",
+            "1,1 -> 7,3\n7,0 -> 1,4\n4,2 -> 4,0\n10,4 -> 1,1",
+        ];
+        for (s, expected) in sloped.iter().zip([0, 1, 1, 3]) {
+            let lines = parse(s).unwrap();
+            for backend in [Backend::Dense, Backend::Sparse, Backend::Analytic] {
+                assert_eq!(
+                    expected,
+                    overlaps(&lines, true, backend),
+                    "{} {:?}",
+                    s,
+                    backend
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn analytic() {
+        // two long diagonals crossing, overlapping horizontals, and crossings
+        // both inside and outside of where the horizontals overlap
+        let lines = parse(
+            "\
+0,0 -> 3000000,3000000
+3000000,0 -> 0,3000000
+0,5 -> 4000000,5
+5000000,5 -> 1000000,5",
+        )
+        .unwrap();
+        assert_eq!(3_000_001, overlaps(&lines, false, Backend::Analytic));
+        assert_eq!(3_000_003, overlaps(&lines, true, Backend::Analytic));
+
+        // other slopes cross on whole points, or between them
+        let lines = parse("0,0 -> 4,2\n0,2 -> 4,0\n8,0 -> 10,1\n8,1 -> 10,0").unwrap();
+        assert_eq!(1, overlaps(&lines, true, Backend::Analytic));
+
+        // collinear with a gap, and a single point on a line
+        let lines = parse("0,0 -> 2,2\n3,3 -> 5,5\n4,4 -> 4,4").unwrap();
+        assert_eq!(1, overlaps(&lines, true, Backend::Analytic));
+    }
+
+    #[test]
+    fn basic_parse() {
+        let ans = vec![
+            Line {
+                x1: 0,
+                y1: 0,
+                x2: 0,
+                y2: 1,
+            },
+            Line {
+                x1: 2,
+                y1: 2,
+                x2: 3,
+                y2: 2,
+            },
+        ];
+
+        assert_eq!(Ok(ans), parse(BASIC))
+    }
+
+    #[test]
+    fn bad_parse() {
+        let e = parse("0,0 -> 0,1\n2,2 -> 3").unwrap_err();
+        assert_eq!(ParseError::new("3", "a point like `x,y`").shifted(1), e);
+
+        let e = parse("0,0 -> 0,-1").unwrap_err();
+        assert_eq!(
+            ParseError::new("-1", "a coordinate from 0 to 4294967295"),
+            e
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(5, day05::parse, day05::part1, day05::part2);
+}
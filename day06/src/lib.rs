@@ -0,0 +1,422 @@
+use std::cmp::max;
+use std::fmt;
+
+use aoc_common::parse::{comma_separated, ParseError};
+
+const PART1_DAYS: usize = 80;
+const PART2_DAYS: usize = 256;
+
+/// Index represents the stage, value is the number of fishes at that stage
+pub type Input = Vec<u64>;
+
+/// Convert string of comma separated numbers into lanternfish at each stage
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Species::LANTERNFISH.population(&comma_separated(s)?)
+}
+
+/// Return the number of lanternfish after PART1_DAYS days
+pub fn part1(fishes: &Input) -> Result<u64, ParseError> {
+    total(fishes, PART1_DAYS)
+}
+
+/// Return the number of lanternfish after PART2_DAYS days
+pub fn part2(fishes: &Input) -> Result<u64, ParseError> {
+    total(fishes, PART2_DAYS)
+}
+
+/// The number of lanternfish after days, or an error if there are too many
+fn total(fishes: &Input, days: usize) -> Result<u64, ParseError> {
+    match Species::LANTERNFISH.series(fishes, days) {
+        Some(series) => Ok(series[days]),
+        None => Err(ParseError::whole(format!(
+            "the population outgrows a u64 within {} days",
+            days
+        ))),
+    }
+}
+
+/// How a kind of fish breeds. A fish whose timer is at 0 has a baby, and the
+/// next day its timer is at reset and the baby's is at newborn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Species {
+    pub reset: usize,
+    pub newborn: usize,
+}
+
+impl Species {
+    pub const LANTERNFISH: Species = Species {
+        reset: 6,
+        newborn: 8,
+    };
+
+    /// How many timer values a fish can have
+    pub fn stages(&self) -> usize {
+        max(self.reset, self.newborn) + 1
+    }
+
+    /// Count how many fish have each timer
+    pub fn population(&self, timers: &[usize]) -> Result<Vec<u64>, ParseError> {
+        let mut counts = vec![0; self.stages()];
+
+        for &t in timers {
+            // Any fish outside of our stages would silently go missing
+            let count = counts.get_mut(t).ok_or_else(|| {
+                ParseError::new(t, format!("a timer from 0 to {}", self.stages() - 1))
+            })?;
+            *count += 1;
+        }
+
+        Ok(counts)
+    }
+
+    /// The total population at the start and after each day up to days, from
+    /// the number of fish at each stage. None if the population outgrows a
+    /// u64 by then, which after_days::<Big> can count past.
+    pub fn series(&self, fishes: &[u64], days: usize) -> Option<Vec<u64>> {
+        let mut fishes = fishes.to_vec();
+        fishes.resize(self.stages(), 0);
+        let sum = |f: &[u64]| f.iter().try_fold(0_u64, |acc, &n| acc.checked_add(n));
+
+        let mut series = vec![sum(&fishes)?];
+        for _ in 0..days {
+            self.day_update(&mut fishes)?;
+            series.push(sum(&fishes)?);
+        }
+        Some(series)
+    }
+
+    /// The total population after days, from the number of fish at each stage.
+    /// This takes O(log days) matrix multiplications, so days can be huge as
+    /// long as the count type can hold the answer. None if it can't.
+    pub fn after_days<T: Count>(&self, fishes: &[u64], days: u64) -> Option<T> {
+        let n = self.stages();
+        let step = self.transition::<T>().pow(days)?;
+
+        (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(_, j)| j < fishes.len())
+            .try_fold(T::from_u64(0), |acc, (i, j)| {
+                acc.add(&step.get(i, j).mul(&T::from_u64(fishes[j]))?)
+            })
+    }
+
+    /// The matrix taking the fish at each stage on one day to the fish at
+    /// each stage on the next.
+    fn transition<T: Count>(&self) -> Matrix<T> {
+        Matrix::from_fn(self.stages(), |i, j| {
+            // Fish move down a stage, and fish at 0 go to reset and make a
+            // newborn
+            let moved = j == i + 1;
+            let born = j == 0 && i == self.newborn;
+            let reset = j == 0 && i == self.reset;
+            T::from_u64(moved as u64 + born as u64 + reset as u64)
+        })
+    }
+
+    /// Move the fishes to their next state, or None if a stage overflows
+    fn day_update(&self, f: &mut [u64]) -> Option<()> {
+        let birthing = f[0];
+
+        // Move fish from timer i+1 to timer i
+        f.rotate_left(1);
+
+        // The fish rotated round to the end are the ones who gave birth, so
+        // they go back to reset and each add a newborn
+        *f.last_mut().unwrap() = 0;
+        f[self.reset] = f[self.reset].checked_add(birthing)?;
+        f[self.newborn] = f[self.newborn].checked_add(birthing)?;
+        Some(())
+    }
+}
+
+/// A number that populations can be counted in. Adding and multiplying give
+/// None if the result doesn't fit.
+pub trait Count: Clone {
+    fn from_u64(n: u64) -> Self;
+    fn add(&self, other: &Self) -> Option<Self>;
+    fn mul(&self, other: &Self) -> Option<Self>;
+}
+
+/// Populations that don't fit are None, use Big for those.
+impl Count for u128 {
+    fn from_u64(n: u64) -> u128 {
+        n.into()
+    }
+
+    fn add(&self, other: &u128) -> Option<u128> {
+        self.checked_add(*other)
+    }
+
+    fn mul(&self, other: &u128) -> Option<u128> {
+        self.checked_mul(*other)
+    }
+}
+
+/// A count modulo M.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modular<const M: u64>(pub u64);
+
+impl<const M: u64> Count for Modular<M> {
+    fn from_u64(n: u64) -> Self {
+        Modular(n % M)
+    }
+
+    fn add(&self, other: &Self) -> Option<Self> {
+        Some(Modular(
+            ((self.0 as u128 + other.0 as u128) % M as u128) as u64,
+        ))
+    }
+
+    fn mul(&self, other: &Self) -> Option<Self> {
+        Some(Modular(
+            (self.0 as u128 * other.0 as u128 % M as u128) as u64,
+        ))
+    }
+}
+
+/// An unsigned number of any size, as base 2^32 digits with the least
+/// significant first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Big(Vec<u32>);
+
+impl Big {
+    /// Drop leading zero digits, so every number has one representation
+    fn trimmed(mut self) -> Big {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+}
+
+impl Count for Big {
+    fn from_u64(n: u64) -> Big {
+        Big(vec![n as u32, (n >> 32) as u32]).trimmed()
+    }
+
+    fn add(&self, other: &Big) -> Option<Big> {
+        let mut digits = Vec::with_capacity(max(self.0.len(), other.0.len()) + 1);
+        let mut carry = 0;
+        for i in 0..max(self.0.len(), other.0.len()) {
+            let sum =
+                *self.0.get(i).unwrap_or(&0) as u64 + *other.0.get(i).unwrap_or(&0) as u64 + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+        Some(Big(digits).trimmed())
+    }
+
+    fn mul(&self, other: &Big) -> Option<Big> {
+        let mut digits = vec![0_u32; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.0.iter().enumerate() {
+                let cur = digits[i + j] as u64 + a as u64 * b as u64 + carry;
+                digits[i + j] = cur as u32;
+                carry = cur >> 32;
+            }
+            digits[i + other.0.len()] = carry as u32;
+        }
+        Some(Big(digits).trimmed())
+    }
+}
+
+impl fmt::Display for Big {
+    /// Print in decimal, by dividing out 9 decimal digits at a time
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 1_000_000_000;
+
+        let mut n = self.0.clone();
+        let mut chunks = Vec::new();
+        while !n.is_empty() {
+            let mut rem = 0;
+            for d in n.iter_mut().rev() {
+                let cur = (rem << 32) | *d as u64;
+                *d = (cur / CHUNK) as u32;
+                rem = cur % CHUNK;
+            }
+            chunks.push(rem);
+            n = Big(n).trimmed().0;
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{}", first)?;
+                rest.iter().rev().try_for_each(|c| write!(f, "{:09}", c))
+            }
+        }
+    }
+}
+
+/// A square matrix, stored row by row.
+struct Matrix<T> {
+    n: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Count> Matrix<T> {
+    fn from_fn(n: usize, f: impl Fn(usize, usize) -> T) -> Matrix<T> {
+        Matrix {
+            n,
+            cells: (0..n * n).map(|c| f(c / n, c % n)).collect(),
+        }
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self.cells[i * self.n + j].clone()
+    }
+
+    fn mul(&self, other: &Matrix<T>) -> Option<Matrix<T>> {
+        let cells = (0..self.n * self.n)
+            .map(|c| {
+                let (i, j) = (c / self.n, c % self.n);
+                (0..self.n).try_fold(T::from_u64(0), |acc, k| {
+                    acc.add(&self.get(i, k).mul(&other.get(k, j))?)
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Matrix { n: self.n, cells })
+    }
+
+    /// Raise to the power e by squaring
+    fn pow(&self, mut e: u64) -> Option<Matrix<T>> {
+        let mut result = Matrix::from_fn(self.n, |i, j| T::from_u64((i == j) as u64));
+        let mut base = Matrix {
+            n: self.n,
+            cells: self.cells.clone(),
+        };
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(&base)?;
+            }
+            // squaring past the last bit could overflow for nothing
+            e >>= 1;
+            if e > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_example() {
+        fixtures::check(6, parse, part1, part2);
+    }
+
+    #[test]
+    fn bad_timer() {
+        assert_eq!(
+            Err(ParseError::new(9, "a timer from 0 to 8")),
+            parse("3,4,9,1")
+        );
+    }
+
+    #[test]
+    fn series() {
+        let fishes = parse(fixtures::input(6, 1)).unwrap();
+        let series = Species::LANTERNFISH.series(&fishes, 18).unwrap();
+
+        assert_eq!(19, series.len());
+        assert_eq!([5, 5, 6, 7, 9, 10], series[..6]);
+        assert_eq!(26, series[18]);
+    }
+
+    #[test]
+    fn other_species() {
+        // babies that can breed the next day, and parents that wait 2 days
+        let quick = Species {
+            reset: 2,
+            newborn: 0,
+        };
+        let fishes = quick.population(&[0]).unwrap();
+        assert_eq!(Some(vec![1, 2, 3, 4, 6]), quick.series(&fishes, 4));
+
+        // quick fish outgrow a u64 in a couple of hundred days
+        assert_eq!(None, quick.series(&fishes, 200));
+        assert!(
+            quick
+                .after_days::<Big>(&fishes, 200)
+                .unwrap()
+                .to_string()
+                .len()
+                > 20
+        );
+
+        assert_eq!(
+            ParseError::new(3, "a timer from 0 to 2"),
+            quick.population(&[1, 3]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn fast_forward() {
+        let fish = Species::LANTERNFISH;
+        let fishes = parse(fixtures::input(6, 1)).unwrap();
+
+        for days in [0, 1, 18, 80, 256] {
+            let slow = fish.series(&fishes, days as usize).unwrap()[days as usize];
+            assert_eq!(Some(slow as u128), fish.after_days::<u128>(&fishes, days));
+            assert_eq!(
+                slow.to_string(),
+                fish.after_days::<Big>(&fishes, days).unwrap().to_string()
+            );
+        }
+
+        // Past what u128 can hold, the big count still agrees with the
+        // modular one
+        const M: u64 = 1_000_000_007;
+        let big = fish.after_days::<Big>(&fishes, 2000).unwrap().to_string();
+        let modular = fish.after_days::<Modular<M>>(&fishes, 2000);
+        let rem = big
+            .bytes()
+            .fold(0, |acc, d| (acc * 10 + (d - b'0') as u64) % M);
+        assert!(big.len() > 40);
+        assert_eq!(Some(Modular(rem)), modular);
+
+        // A trillion days is no trouble mod M, but is far too many for a u128
+        let trillion = 1_000_000_000_000;
+        assert!(fish.after_days::<Modular<M>>(&fishes, trillion).is_some());
+        assert_eq!(None, fish.after_days::<u128>(&fishes, trillion));
+        assert_eq!(None, fish.after_days::<u128>(&fishes, 2000));
+
+        // but counting each day only goes as far as a u64 does
+        assert_eq!(None, fish.series(&fishes, 2000));
+        assert_eq!(
+            "the population outgrows a u64 within 256 days",
+            part2(&vec![u64::MAX / 2; 9]).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn big_display() {
+        assert_eq!("0", Big::from_u64(0).to_string());
+        assert_eq!(
+            "340282366920938463463374607431768211456",
+            Big::from_u64(1 << 32)
+                .mul(&Big::from_u64(1 << 32))
+                .and_then(|b| b.mul(&Big::from_u64(1 << 32)))
+                .and_then(|b| b.mul(&Big::from_u64(1 << 32)))
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            u64::MAX as u128 + 1_000_000_000,
+            Big::from_u64(u64::MAX)
+                .add(&Big::from_u64(1_000_000_000))
+                .unwrap()
+                .to_string()
+                .parse::<u128>()
+                .unwrap()
+        );
+    }
+}
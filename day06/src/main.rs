@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(6, day06::parse, day06::part1, day06::part2);
+}
@@ -0,0 +1,229 @@
+use aoc_common::parse::{comma_separated, ParseError};
+
+/// The crabs' horizontal positions, sorted.
+pub type Input = Vec<i32>;
+
+/// Parse the crab positions, sorting them so the median is easy to find
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut v = comma_separated(s)?;
+    v.sort_unstable();
+    Ok(v)
+}
+
+/// Return the least fuel needed to align the crabs when every step costs 1
+pub fn part1(v: &Input) -> Result<i64, ParseError> {
+    cheapest_linear(v).map(|a| a.fuel).ok_or_else(no_crabs)
+}
+
+/// Return the least fuel needed to align the crabs when each step costs 1
+/// more than the last
+pub fn part2(v: &Input) -> Result<i64, ParseError> {
+    cheapest_triangular(v).map(|a| a.fuel).ok_or_else(no_crabs)
+}
+
+/// The error for when there are no crabs to line up
+fn no_crabs() -> ParseError {
+    ParseError::whole("there are no crabs to line up")
+}
+
+/// How much fuel it takes a crab to move some distance. Costs must never go
+/// down as the distance goes up, and must be convex (each extra step costs at
+/// least as much as the last) for minimise to find the cheapest alignment.
+pub trait CostFn {
+    fn cost(&self, distance: u32) -> i64;
+}
+
+/// Any function from distance to fuel is a cost.
+impl<F: Fn(u32) -> i64> CostFn for F {
+    fn cost(&self, distance: u32) -> i64 {
+        self(distance)
+    }
+}
+
+/// Every step costs 1.
+pub struct Linear;
+
+impl CostFn for Linear {
+    fn cost(&self, distance: u32) -> i64 {
+        distance.into()
+    }
+}
+
+/// Each step costs 1 more than the last.
+pub struct Triangular;
+
+impl CostFn for Triangular {
+    fn cost(&self, distance: u32) -> i64 {
+        triangular(distance)
+    }
+}
+
+/// Moving d steps costs d^2.
+pub struct Quadratic;
+
+impl CostFn for Quadratic {
+    fn cost(&self, distance: u32) -> i64 {
+        let d = i64::from(distance);
+        d * d
+    }
+}
+
+/// The fuel to line every crab up at position.
+pub fn fuel(v: &[i32], position: i32, c: &impl CostFn) -> i64 {
+    v.iter().map(|&i| c.cost(i.abs_diff(position))).sum()
+}
+
+/// The cheapest alignment for any convex cost, or None if there are no crabs.
+/// The total fuel is then convex in the position too, so binary search for
+/// where it stops going down. The best position is always between the
+/// outermost crabs, so v has to be sorted.
+pub fn minimise(v: &[i32], c: &impl CostFn) -> Option<Alignment> {
+    let (mut low, mut high) = (*v.first()?, *v.last()?);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if fuel(v, mid + 1, c) < fuel(v, mid, c) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Some(Alignment {
+        position: low,
+        fuel: fuel(v, low, c),
+    })
+}
+
+/// Where to line the crabs up, and how much fuel it takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: i32,
+    pub fuel: i64,
+}
+
+/// The cheapest alignment when every step costs 1, or None if there are no
+/// crabs. v has to be sorted.
+pub fn cheapest_linear(v: &[i32]) -> Option<Alignment> {
+    let position = median(v)?;
+    Some(Alignment {
+        position,
+        fuel: fuel(v, position, &Linear),
+    })
+}
+
+/// The cheapest alignment when each step costs 1 more than the last, or None
+/// if there are no crabs.
+pub fn cheapest_triangular(v: &[i32]) -> Option<Alignment> {
+    // The total cost is (sum of d^2 + sum of |d|) / 2. The squares are least
+    // at the mean, and moving by x changes the |d|s by at most n * x, which
+    // can't beat the squares growing by n * x^2 once x is past 1/2. So the
+    // best position is within 1/2 of the mean, and checking the whole numbers
+    // either side of it is enough.
+    let m = mean(v)?;
+    (m - 1..=m + 1)
+        .map(|position| Alignment {
+            position,
+            fuel: fuel(v, position, &Triangular),
+        })
+        .min_by_key(|a| (a.fuel, a.position))
+}
+
+/// The fuel to move d steps when each step costs 1 more than the last, which
+/// is 1 + 2 + ... + d
+fn triangular(d: u32) -> i64 {
+    let d = i64::from(d);
+    d * (d + 1) / 2
+}
+
+/// Find a median of a sorted vector of numbers
+/// A median is guaranteed to optimize, for x, sum(|arr_elements-x|)
+/// https://math.stackexchange.com/questions/113270/the-median-minimizes-the-sum-of-absolute-deviations-the-ell-1-norm
+fn median(v: &[i32]) -> Option<i32> {
+    v.get(v.len() / 2).copied()
+}
+
+/// The mean of the positions, rounded down
+fn mean(v: &[i32]) -> Option<i32> {
+    let sum = v.iter().map(|&i| i as i64).sum::<i64>();
+    sum.checked_div_euclid(v.len() as i64).map(|m| m as i32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_example() {
+        fixtures::check(7, parse, part1, part2);
+    }
+
+    #[test]
+    fn alignments() {
+        let v = parse(fixtures::input(7, 1)).unwrap();
+
+        assert_eq!(
+            Alignment {
+                position: 2,
+                fuel: 37
+            },
+            cheapest_linear(&v).unwrap()
+        );
+        assert_eq!(
+            Alignment {
+                position: 5,
+                fuel: 168
+            },
+            cheapest_triangular(&v).unwrap()
+        );
+
+        assert_eq!(None, cheapest_linear(&[]));
+        assert_eq!(None, cheapest_triangular(&[]));
+        assert_eq!(None, minimise(&[], &Quadratic));
+        assert_eq!(Err(no_crabs()), part1(&vec![]));
+    }
+
+    #[test]
+    fn exact() {
+        // Check against every position on small and lopsided inputs, which a
+        // search around the mean by a fraction of the input misses
+        for s in [
+            "1",
+            "0,100",
+            "0,0,0,0,0,0,0,0,0,1000",
+            "-5,3,3,3,9",
+            "7,7,8",
+        ] {
+            let v = parse(s).unwrap();
+            let brute = (v[0]..=v[v.len() - 1])
+                .map(|p| fuel(&v, p, &Triangular))
+                .min()
+                .unwrap();
+            assert_eq!(brute, cheapest_triangular(&v).unwrap().fuel, "{}", s);
+            assert_eq!(brute, minimise(&v, &Triangular).unwrap().fuel, "{}", s);
+        }
+    }
+
+    #[test]
+    fn costs() {
+        let v = parse(fixtures::input(7, 1)).unwrap();
+
+        assert_eq!(
+            cheapest_linear(&v).map(|a| a.fuel),
+            minimise(&v, &Linear).map(|a| a.fuel)
+        );
+        assert_eq!(cheapest_triangular(&v), minimise(&v, &Triangular));
+
+        // Squares are least around the mean, 4.9
+        assert_eq!(5, minimise(&v, &Quadratic).unwrap().position);
+
+        // Only moving far is expensive
+        let far = |d: u32| if d > 4 { i64::from(d - 4) * 100 } else { 0 };
+        let a = minimise(&v, &far).unwrap();
+        assert_eq!(
+            a.fuel,
+            (v[0]..=16).map(|p| fuel(&v, p, &far)).min().unwrap()
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(7, day07::parse, day07::part1, day07::part2);
+}
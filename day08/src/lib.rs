@@ -0,0 +1,396 @@
+use std::collections::BTreeSet;
+
+use aoc_common::parse::{self, ParseError};
+
+const UNIQ_LENS: [usize; 4] = [2, 3, 4, 7];
+
+/// How many segments, and so wires, a display has.
+const SEGMENTS: usize = 7;
+/// Every segment on.
+const ALL: u8 = (1 << SEGMENTS) - 1;
+
+/// The segments each digit lights up, with segment a as bit 0 up to g as bit 6.
+const DIGITS: [u8; 10] = [
+    0b1110111, // abcefg
+    0b0100100, // cf
+    0b1011101, // acdeg
+    0b1101101, // acdfg
+    0b0101110, // bcdf
+    0b1101011, // abdfg
+    0b1111011, // abdefg
+    0b0100101, // acf
+    0b1111111, // abcdefg
+    0b1101111, // abcdfg
+];
+
+/// The most output digits an entry can have, so that every reading fits in a
+/// u64.
+const MAX_OUTPUT: usize = 19;
+
+/// Every display: its patterns and the four digit output.
+pub type Input = Vec<Entry>;
+
+/// One line of notes about a display.
+#[derive(Debug)]
+pub struct Entry {
+    /// Each pattern as a bitmask of the wires that are on
+    patterns: Vec<u8>,
+    output: Vec<String>,
+    /// Each output digit as a bitmask, like patterns
+    output_wires: Vec<u8>,
+}
+
+/// Turn every line of puzzle input into an Entry
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    parse::lines(s, Entry::new)
+}
+
+/// Count how many times 1, 4, 7 or 8 appear in the output
+pub fn part1(entries: &Input) -> i32 {
+    entries.iter().map(|e| count_1_4_7_8(&e.output)).sum()
+}
+
+/// Decode every output and add them all up
+pub fn part2(entries: &Input) -> Result<u64, ParseError> {
+    entries
+        .iter()
+        .enumerate()
+        .try_fold(0_u64, |acc, (line, e)| {
+            let value = e.decode().map_err(|err| err.shifted(line))?;
+            acc.checked_add(value)
+                .ok_or_else(|| ParseError::whole("the outputs add up to more than a u64 holds"))
+        })
+}
+
+/// Count the number of 1s, 4s, 7s, and 8s in the vector since they have unique
+/// number of segments on, as stored in UNIQ_LENS
+fn count_1_4_7_8(v: &[String]) -> i32 {
+    v.iter()
+        .filter(|s| UNIQ_LENS.contains(&s.len()))
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+/// What the output of a display could say.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reading {
+    /// Every wiring that fits the notes reads the output the same way
+    Value(u64),
+    /// The wirings that fit read the output as different values, in order
+    Ambiguous(Vec<u64>),
+    /// No wiring fits the notes
+    Impossible,
+}
+
+impl Entry {
+    /// Take one line of puzzle input and turn it into patterns and the output
+    /// message. There can be any number of patterns, the more there are the
+    /// less ambiguous the output is.
+    fn new(s: &str) -> Result<Entry, ParseError> {
+        let (patterns, msg) = s
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(s, "patterns and outputs split by ` | `"))?;
+
+        // wires gives the column in the token, move it to the column in s
+        let masks = |part: &str, start: usize| {
+            tokens(part)
+                .map(|(at, t)| {
+                    wires(t).map_err(|e| {
+                        let column = start + at + e.column.unwrap_or(1);
+                        e.at_column(column)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let msg_start = patterns.len() + 1;
+        let patterns = masks(patterns, 0)?;
+        let output_wires = masks(msg, msg_start)?;
+        let output = msg.split_whitespace().map(String::from).collect::<Vec<_>>();
+        if output.len() > MAX_OUTPUT {
+            return Err(ParseError::new(
+                msg.trim(),
+                format!("at most {} output digits", MAX_OUTPUT),
+            )
+            .at_column(msg_start + 1 + msg.len() - msg.trim_start().len()));
+        }
+
+        Ok(Entry {
+            patterns,
+            output,
+            output_wires,
+        })
+    }
+
+    /// Work out every wiring that fits the patterns and output, and read the
+    /// output with them
+    pub fn read(&self) -> Reading {
+        // outputs are digits shown on the display too, so they narrow down
+        // the wiring as well
+        let notes = self
+            .patterns
+            .iter()
+            .chain(&self.output_wires)
+            .copied()
+            .collect::<Vec<_>>();
+
+        let values = wirings(&notes)
+            .iter()
+            .map(|w| {
+                self.output_wires
+                    .iter()
+                    .fold(0, |acc, &o| acc * 10 + digit(w, o).unwrap() as u64)
+            })
+            .collect::<BTreeSet<_>>();
+
+        match values.len() {
+            0 => Reading::Impossible,
+            1 => Reading::Value(*values.first().unwrap()),
+            _ => Reading::Ambiguous(values.into_iter().collect()),
+        }
+    }
+
+    /// Read the output, or an error if it can't be read one way
+    fn decode(&self) -> Result<u64, ParseError> {
+        match self.read() {
+            Reading::Value(v) => Ok(v),
+            Reading::Ambiguous(values) => Err(ParseError::new(
+                self.output.join(" "),
+                format!(
+                    "an output that only reads one way, not {}",
+                    values
+                        .iter()
+                        .map(u64::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            )),
+            Reading::Impossible => Err(ParseError::new(
+                self.output.join(" "),
+                "patterns and an output that a seven segment display could show",
+            )),
+        }
+    }
+}
+
+/// Every whitespace separated token in s, with the byte it starts at
+fn tokens(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split(char::is_whitespace)
+        .scan(0, |start, t| {
+            let at = *start;
+            *start += t.len() + 1;
+            Some((at, t))
+        })
+        .filter(|(_, t)| !t.is_empty())
+}
+
+/// Turn a pattern like "cfbegad" into a bitmask of the wires that are on
+fn wires(s: &str) -> Result<u8, ParseError> {
+    s.chars().enumerate().try_fold(0, |mask, (i, c)| match c {
+        'a'..='g' if mask & 1 << (c as u8 - b'a') == 0 => Ok(mask | 1 << (c as u8 - b'a')),
+        'a'..='g' => Err(ParseError::new(c, "each wire at most once").at_column(i + 1)),
+        _ => Err(ParseError::new(c, "a wire from `a` to `g`").at_column(i + 1)),
+    })
+}
+
+/// A wiring, where wiring[w] is the segment (as a bit) that wire w lights up.
+/// While searching, wires that haven't been decided yet are 0.
+type Wiring = [u8; SEGMENTS];
+
+/// The segments that the wires in pattern p light up with wiring w
+fn light(w: &Wiring, p: u8) -> u8 {
+    (0..SEGMENTS)
+        .filter(|&i| p >> i & 1 == 1)
+        .fold(0, |acc, i| acc | w[i])
+}
+
+/// The digit pattern p shows with wiring w, if any
+fn digit(w: &Wiring, p: u8) -> Option<usize> {
+    DIGITS.iter().position(|&d| d == light(w, p))
+}
+
+/// Every wiring that shows every pattern as a digit.
+fn wirings(patterns: &[u8]) -> Vec<Wiring> {
+    // Each wire can only light up a segment that every digit with the same
+    // number of segments as a pattern could have on (if the wire is in the
+    // pattern) or off (if it isn't)
+    let mut candidates = [ALL; SEGMENTS];
+    for &p in patterns {
+        let fits = DIGITS
+            .iter()
+            .filter(|d| d.count_ones() == p.count_ones())
+            .collect::<Vec<_>>();
+        let on = fits.iter().fold(0, |acc, &&d| acc | d);
+        let off = fits.iter().fold(0, |acc, &&d| acc | (!d & ALL));
+
+        for (w, c) in candidates.iter_mut().enumerate() {
+            *c &= if p >> w & 1 == 1 { on } else { off };
+        }
+    }
+
+    let mut found = Vec::new();
+    search(&candidates, patterns, &mut [0; SEGMENTS], 0, &mut found);
+    found
+}
+
+/// Try every segment for the undecided wire with the fewest options, as long
+/// as every pattern could still be a digit.
+fn search(
+    candidates: &[u8; SEGMENTS],
+    patterns: &[u8],
+    wiring: &mut Wiring,
+    used: u8,
+    found: &mut Vec<Wiring>,
+) {
+    let next = (0..SEGMENTS)
+        .filter(|&w| wiring[w] == 0)
+        .min_by_key(|&w| (candidates[w] & !used).count_ones());
+
+    let w = match next {
+        Some(w) => w,
+        None => {
+            // Every wire is decided, and it's a permutation since used
+            // segments are never reused
+            if patterns.iter().all(|&p| digit(wiring, p).is_some()) {
+                found.push(*wiring);
+            }
+            return;
+        }
+    };
+
+    let mut options = candidates[w] & !used;
+    while options != 0 {
+        let segment = options & options.wrapping_neg();
+        options &= !segment;
+
+        wiring[w] = segment;
+        if patterns.iter().all(|&p| could_be_digit(wiring, p)) {
+            search(candidates, patterns, wiring, used | segment, found);
+        }
+    }
+    wiring[w] = 0;
+}
+
+/// Whether some digit has every segment the decided wires in p light up, and
+/// none of the segments the decided wires outside of p do
+fn could_be_digit(w: &Wiring, p: u8) -> bool {
+    let on = light(w, p);
+    let off = light(w, !p & ALL);
+
+    DIGITS
+        .iter()
+        .any(|&d| d.count_ones() == p.count_ones() && on & !d == 0 && off & d == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_examples() {
+        fixtures::check(8, parse, part1, part2);
+    }
+
+    #[test]
+    fn web_parse() {
+        let entries = parse(fixtures::input(8, 2)).unwrap();
+
+        assert_eq!(
+            vec!["fdgacbe", "cefdb", "cefbgd", "gcbe"],
+            entries[0].output
+        );
+    }
+
+    #[test]
+    fn missing_split() {
+        let good = fixtures::input(8, 1).trim();
+        let e = parse(&format!("{}\n{}", good, good.replace(" | ", " "))).unwrap_err();
+        assert_eq!(Some(2), e.line);
+        assert_eq!("patterns and outputs split by ` | `", e.expected);
+    }
+
+    #[test]
+    fn missing_pattern() {
+        // 1 is missing, but there's still only one way to wire it
+        let entries =
+            parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb | cdfeb").unwrap();
+        assert_eq!(Reading::Value(5), entries[0].read());
+
+        // Seeing just a 1 and a 4 leaves plenty of wirings, but they all read
+        // the output the same
+        let entries = parse("ab | ab abef").unwrap();
+        assert_eq!(Reading::Value(14), entries[0].read());
+        let entries = parse("| abc abc").unwrap();
+        assert_eq!(Reading::Value(77), entries[0].read());
+    }
+
+    #[test]
+    fn long_output() {
+        let ones = format!("|{}", " ab".repeat(MAX_OUTPUT));
+        let entries = parse(&ones).unwrap();
+        assert_eq!(Reading::Value(1_111_111_111_111_111_111), entries[0].read());
+
+        let e = parse(&format!("{} ab", ones)).unwrap_err();
+        assert_eq!("at most 19 output digits", e.expected);
+        assert_eq!(Some(3), e.column);
+
+        // 8888888888888888888 three times is more than a u64
+        let eights = format!(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |{}\n",
+            " abcdefg".repeat(MAX_OUTPUT)
+        );
+        assert_eq!(
+            Ok(17_777_777_777_777_777_776),
+            part2(&parse(&eights.repeat(2)).unwrap())
+        );
+        assert_eq!(
+            "the outputs add up to more than a u64 holds",
+            part2(&parse(&eights.repeat(3)).unwrap())
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn ambiguous_and_impossible() {
+        // Two five segment digits sharing only three wires are a 2 and a 5,
+        // but nothing says which is which
+        let entries = parse("| abcde abcfg").unwrap();
+        assert_eq!(Reading::Ambiguous(vec![25, 52]), entries[0].read());
+        assert!(part2(&entries)
+            .unwrap_err()
+            .expected
+            .starts_with("an output that only reads one way"));
+
+        let entries = parse("acedgfb | abcdef abcdef").unwrap();
+        assert_eq!(Reading::Ambiguous(vec![0, 66, 99]), entries[0].read());
+
+        // Two different 1s
+        let entries = parse("ab | cd").unwrap();
+        assert_eq!(Reading::Impossible, entries[0].read());
+        assert_eq!(
+            "patterns and an output that a seven segment display could show",
+            part2(&entries).unwrap_err().expected
+        );
+    }
+
+    #[test]
+    fn bad_wire() {
+        let e = parse("abh | ab").unwrap_err();
+        assert_eq!(
+            "line 1, column 3: expected a wire from `a` to `g`, found `h`",
+            e.to_string()
+        );
+
+        // the first of two bad outputs that look the same
+        let e = parse("abc | aa aa").unwrap_err();
+        assert_eq!(
+            "line 1, column 8: expected each wire at most once, found `a`",
+            e.to_string()
+        );
+        let e = parse("ab  ab\tabb | aa").unwrap_err();
+        assert_eq!(Some(10), e.column);
+    }
+}
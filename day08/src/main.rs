@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(8, day08::parse, day08::part1, day08::part2);
+}
@@ -0,0 +1,122 @@
+use std::collections::{BinaryHeap, VecDeque};
+
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::ParseError;
+
+/// The height of every point in the cave.
+pub type Input = Grid<u32>;
+
+/// Given puzzle input, return the height map
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Grid::parse_digits(s)
+}
+
+/// Find the total sum of the height of low points + 1.
+pub fn part1(g: &Input) -> u32 {
+    let mut risk_levels = Vec::new();
+
+    for p in g.points() {
+        let height = g[p];
+        if g.neighbors4(p).all(|n| height < g[n]) {
+            risk_levels.push(height + 1);
+        }
+    }
+
+    risk_levels.iter().sum()
+}
+
+/// Multiply the size of the largest three basins.
+pub fn part2(g: &Input) -> Result<i32, ParseError> {
+    let mut g = spots(g);
+    let mut heap = BinaryHeap::new();
+
+    for p in g.points() {
+        if g[p] == Spot::NotSearched {
+            let size = bfs(&mut g, p);
+            heap.push(size);
+        }
+    }
+
+    let largest = (0..3).filter_map(|_| heap.pop()).collect::<Vec<_>>();
+    if largest.len() < 3 {
+        return Err(ParseError::whole(
+            "the height map has fewer than three basins",
+        ));
+    }
+
+    Ok(largest.iter().product())
+}
+
+#[derive(PartialEq)]
+enum Spot {
+    Wall,
+    Searched,
+    NotSearched,
+}
+
+/// Given the height map, return a grid of Spots
+fn spots(g: &Grid<u32>) -> Grid<Spot> {
+    Grid::from_fn(g.width(), g.height(), |p| match g[p] {
+        9 => Spot::Wall,
+        _ => Spot::NotSearched,
+    })
+}
+
+/// Run BFS on a grid of Spots starting at p, returning the size of the area
+fn bfs(map: &mut Grid<Spot>, p: Point) -> i32 {
+    // init bfs
+    let mut queue = VecDeque::from([p]);
+    map[p] = Spot::Searched;
+    let mut size = 1;
+
+    while !queue.is_empty() {
+        let p = queue.pop_front().unwrap();
+
+        let neighbors = map
+            .neighbors4(p)
+            .filter(|&n| map[n] == Spot::NotSearched)
+            .collect::<Vec<_>>();
+
+        // Add unsearched neighbors to the queue and mark as searched
+        for n in neighbors {
+            map[n] = Spot::Searched;
+            size += 1;
+            queue.push_back(n);
+        }
+    }
+
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    // Note: this is 10x5
+    const WEB_INPUT: &str = fixtures::input(9, 1);
+
+    #[test]
+    fn web_example() {
+        fixtures::check(9, parse, part1, part2);
+    }
+
+    #[test]
+    fn bfs_test() {
+        let mut g = spots(&parse(WEB_INPUT).unwrap());
+
+        assert_eq!(3, bfs(&mut g, (0, 0)));
+        assert_eq!(9, bfs(&mut g, (5, 0)));
+        assert_eq!(14, bfs(&mut g, (2, 1)));
+        assert_eq!(9, bfs(&mut g, (7, 2)));
+    }
+
+    #[test]
+    fn too_few_basins() {
+        for s in ["9", "19\n99", "1919"] {
+            let e = part2(&parse(s).unwrap()).unwrap_err();
+            assert_eq!("the height map has fewer than three basins", e.to_string());
+        }
+        assert_eq!(Ok(1), part2(&parse("19191").unwrap()));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(9, day09::parse, day09::part1, day09::part2);
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::parse::{self, ParseError};
+
+/// Every line of the nav subsystem, checked.
+pub type Input = Vec<Line>;
+
+/// What checking a line of the nav subsystem found.
+#[derive(Debug, PartialEq)]
+pub enum Line {
+    /// The first improper close character
+    Corrupted(char),
+    /// Every character still open at the end, innermost last
+    Incomplete(Vec<char>),
+}
+
+/// Given puzzle input of a nav subsystem, check every line
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    parse::lines(s, check)
+}
+
+/// Return the syntax error score
+pub fn part1(lines: &Input) -> i32 {
+    let error_score = HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
+
+    lines
+        .iter()
+        .map(|l| match l {
+            Line::Corrupted(c) => error_score[c],
+            Line::Incomplete(_) => 0,
+        })
+        .sum()
+}
+
+/// Return the median autocomplete score
+pub fn part2(lines: &Input) -> Result<u64, ParseError> {
+    let mut scores: Vec<u64> = lines
+        .iter()
+        .filter_map(|l| match l {
+            Line::Incomplete(stack) => Some(autocomplete_score(stack)),
+            Line::Corrupted(_) => None,
+        })
+        .collect();
+
+    scores.sort_unstable();
+    scores
+        .get(scores.len() / 2)
+        .copied()
+        .ok_or_else(|| ParseError::whole("no line is incomplete"))
+}
+
+/// Taking one line of input, find its first improper close or what is left open.
+fn check(s: &str) -> Result<Line, ParseError> {
+    let open_chars = HashSet::from(['(', '[', '{', '<']);
+    let close_chars = HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
+
+    let mut stack = Vec::new();
+
+    for (i, c) in s.chars().enumerate() {
+        if open_chars.contains(&c) {
+            // Add open character to stack
+            stack.push(c);
+        } else if !stack.is_empty() && c == close_chars[stack.last().unwrap()] {
+            // We have successfully closed the last open, remove from stack
+            stack.pop();
+        } else if close_chars.values().any(|&close| close == c) {
+            // This is an improper close char, return it as our first error
+            return Ok(Line::Corrupted(c));
+        } else {
+            return Err(ParseError::new(c, "a bracket").at_column(i + 1));
+        }
+    }
+
+    // We never had an improper close
+    Ok(Line::Incomplete(stack))
+}
+
+/// Run backwards through the stack, map to close tokens, fold for autocorrect score
+fn autocomplete_score(stack: &[char]) -> u64 {
+    let close_chars = HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
+    let autocomplete_score = HashMap::from([(')', 1), (']', 2), ('}', 3), ('>', 4)]);
+
+    stack
+        .iter()
+        .rev()
+        .map(|c| autocomplete_score[&close_chars[c]])
+        .fold(0, |acc, i| acc * 5 + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_example() {
+        fixtures::check(10, parse, part1, part2);
+    }
+
+    #[test]
+    fn bad_character() {
+        assert_eq!(
+            Err(ParseError::new('x', "a bracket").at_column(3).shifted(1)),
+            parse("[]\n[(x)]")
+        );
+    }
+
+    #[test]
+    fn nothing_incomplete() {
+        let lines = parse("(]\n[>").unwrap();
+        assert_eq!(
+            "no line is incomplete",
+            part2(&lines).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(10, day10::parse, day10::part1, day10::part2);
+}
@@ -0,0 +1,105 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::ParseError;
+
+const NUM_OF_STEPS: i32 = 100;
+
+/// The energy level of every dumbo octopus.
+pub type Input = Grid<u32>;
+
+/// Given puzzle input, return the grid of dumbo octopuses
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Grid::parse_digits(s)
+}
+
+/// Determine the number of flashes after NUM_OF_STEPS steps.
+pub fn part1(g: &Input) -> u32 {
+    let mut g = g.clone();
+
+    let mut flashes = 0;
+    for _ in 0..NUM_OF_STEPS {
+        flashes += step(&mut g);
+    }
+
+    flashes
+}
+
+/// Determine how many steps are needed to have all octopi flash at once
+pub fn part2(g: &Input) -> u32 {
+    let mut g = g.clone();
+
+    let mut steps = 0;
+    while !g.iter().all(|&i| i == 0) {
+        steps += 1;
+        step(&mut g);
+    }
+
+    steps
+}
+
+/// Given a grid, evaluate one step and return the number of flashes from that step
+fn step(g: &mut Grid<u32>) -> u32 {
+    // First, increase all octopus energy by 1
+    for p in g.iter_mut() {
+        *p += 1;
+    }
+
+    let mut flashes = 0;
+    let mut flashers = gen_flashers(g);
+
+    // These are octopi that need to flash
+    while !flashers.is_empty() {
+        for p in flashers.iter() {
+            // We are flashing - set the octopus to 0
+            g[*p] = 0;
+            flashes += 1;
+
+            // Increase all adjacent by 1
+            for a in g.neighbors8(*p) {
+                // octopi that have already flashed this turn are already 0
+                if g[a] != 0 {
+                    g[a] += 1;
+                }
+            }
+        }
+
+        // We have flashed all nines. Now update to see if there are more to flash
+        flashers = gen_flashers(g);
+    }
+
+    flashes
+}
+
+/// Given a grid, return a list of points that need to flash
+fn gen_flashers(g: &Grid<u32>) -> Vec<Point> {
+    g.points().filter(|&p| g[p] > 9).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::fixtures;
+
+    #[test]
+    fn web_example() {
+        fixtures::check(11, parse, part1, part2);
+    }
+
+    #[test]
+    fn step_test() {
+        let s = fixtures::input(11, 1);
+
+        let mut g = Grid::parse_digits(s).unwrap();
+
+        step(&mut g);
+
+        let ans = [
+            [3, 4, 5, 4, 3],
+            [4, 0, 0, 0, 4],
+            [5, 0, 0, 0, 5],
+            [4, 0, 0, 0, 4],
+            [3, 4, 5, 4, 3],
+        ];
+
+        assert!(ans.iter().flatten().zip(g.iter()).all(|(a, g)| a == g));
+    }
+}
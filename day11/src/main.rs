@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(11, day11::parse, day11::part1, day11::part2);
+}
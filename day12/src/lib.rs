@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use aoc_common::parse::{self, ParseError};
+
+const START: &str = "start";
+const END: &str = "end";
+
+/// The cave system, from cave name to node.
+pub type Input = HashMap<String, Node>;
+
+/// Given puzzle cave input, parse into a hashmap of string to node.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut g = HashMap::new();
+
+    let edges = parse::lines(s, |l| match l.split_once('-') {
+        Some((i1, i2)) if !i1.is_empty() && !i2.is_empty() => Ok((i1, i2)),
+        _ => Err(ParseError::new(l, "two caves joined by `-`")),
+    })?;
+
+    for (i1, i2) in edges {
+        let n1 = g.entry(i1.to_string()).or_insert_with(|| Node::new(i1));
+        n1.neighbors.push(i2.to_string());
+
+        let n2 = g.entry(i2.to_string()).or_insert_with(|| Node::new(i2));
+        n2.neighbors.push(i1.to_string());
+    }
+
+    // Every path begins here, so it has to exist
+    if !g.contains_key(START) {
+        return Err(ParseError::whole(format!("there is no `{}` cave", START)));
+    }
+
+    Ok(g)
+}
+
+/// Determine the number of paths that visit small caves at most once.
+pub fn part1(g: &Input) -> u32 {
+    all_paths(g, false)
+}
+
+/// Determine the number of paths that can visit one small cave twice, and all
+/// other small caves once at most.
+pub fn part2(g: &Input) -> u32 {
+    all_paths(g, true)
+}
+
+#[derive(Clone)]
+struct Path<'a> {
+    path: Vec<&'a str>,
+    visited_small_twice: bool,
+}
+
+impl Path<'_> {
+    // Generate a new path that starts at START. A path that can't revisit a
+    // small cave acts like it already has.
+    fn new(can_revisit: bool) -> Path<'static> {
+        Path {
+            path: vec![START],
+            visited_small_twice: !can_revisit,
+        }
+    }
+}
+
+/// Given a graph, return the number of paths that visit small caves at most
+/// once (except for one small cave twice if can_revisit), starting from START
+/// and ending at END.
+fn all_paths(g: &Input, can_revisit: bool) -> u32 {
+    // Key idea: do BFS but store the entire path rather than just the node in the queue
+    // Start by enqueuing our start node
+    let mut queue = vec![Path::new(can_revisit)];
+    let mut paths = vec![];
+
+    while let Some(curr_path) = queue.pop() {
+        let curr = curr_path.path.last().unwrap();
+
+        if curr == &END {
+            // This is a complete path! Don't add anything to queue
+            paths.push(curr_path);
+            continue;
+        }
+
+        for n in g[*curr].neighbors.iter().map(|s| &g[s]) {
+            if !curr_path.visited_small_twice
+                && n.small
+                && n.name != START
+                && curr_path.path.iter().filter(|&s| s == &n.name).count() == 1
+            {
+                // Special case: we have a small neighbor that we've visited once
+                // before. Make new path with it and note that we've visited
+                // before
+                queue.push({
+                    let mut next_path = curr_path.clone();
+                    next_path.path.push(&n.name);
+                    next_path.visited_small_twice = true;
+                    next_path
+                });
+            } else if !n.small || !curr_path.path.contains(&n.name.as_str()) {
+                // Otherwise not small nodes can be visited as many times as we
+                // want, or we can add small nodes we've never visited before
+                queue.push({
+                    let mut next_path = curr_path.clone();
+                    next_path.path.push(&n.name);
+                    next_path
+                });
+            }
+        }
+    }
+
+    paths.len().try_into().unwrap()
+}
+
+/// Node in a graph.
+#[derive(Debug)]
+pub struct Node {
+    neighbors: Vec<String>,
+    name: String,
+    small: bool,
+}
+
+impl Node {
+    // Create a blank node with its given name
+    fn new(s: &str) -> Node {
+        Node {
+            neighbors: Vec::new(),
+            name: s.to_string(),
+            small: s.chars().next().unwrap().is_lowercase(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const WEB_INPUT_1: &str = fixtures::input(12, 1);
+
+    #[test]
+    fn basic_parse_test() {
+        let g = parse(WEB_INPUT_1).unwrap();
+
+        // this loop spot checks "A" - not a through test, but I
+        // checked everything with a dbg! earlier
+        for n in ["start", "c", "b", "end"] {
+            assert!(g["A"].neighbors.iter().any(|s| s == n));
+        }
+
+        assert!(g["A"].neighbors.len() == 4);
+    }
+
+    #[test]
+    fn bad_parse() {
+        let e = parse("start-A\nA=b").unwrap_err();
+        assert_eq!(
+            ParseError::new("A=b", "two caves joined by `-`").shifted(1),
+            e
+        );
+
+        let e = parse("A-b\nb-end").unwrap_err();
+        assert_eq!("there is no `start` cave", e.expected);
+    }
+
+    #[test]
+    fn web_examples() {
+        fixtures::check(12, parse, part1, part2);
+    }
+}
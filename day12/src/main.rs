@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(12, day12::parse, day12::part1, day12::part2);
+}
@@ -0,0 +1,170 @@
+use aoc_common::grid::Grid;
+use aoc_common::parse::{self, ParseError};
+
+/// Every point on the paper with a dot on it.
+type Dots = Vec<(u32, u32)>;
+
+/// The transparent paper and how to fold it.
+#[derive(Debug)]
+pub struct Input {
+    dots: Dots,
+    folds: Vec<Instr>,
+}
+
+/// Given puzzle input, return a vector of points with dots and a vector of instructions.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    // Split at empty newline
+    let (points, instrs) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::whole("no blank line between the dots and the folds"))?;
+
+    let points = parse::lines(points, |s| match s.split_once(',') {
+        Some((x, y)) => Ok((
+            parse::token(x, "a coordinate")?,
+            parse::token(y, "a coordinate")?,
+        )),
+        None => Err(ParseError::new(s, "a dot like `x,y`")),
+    })?;
+
+    // The folds start after every dot and the empty line
+    let instrs = parse::lines(instrs, |s| {
+        match s
+            .strip_prefix("fold along ")
+            .and_then(|f| f.split_once('='))
+        {
+            Some(("x", i)) => Ok(Instr::X(parse::token(i, "a fold position")?)),
+            Some(("y", i)) => Ok(Instr::Y(parse::token(i, "a fold position")?)),
+            _ => Err(ParseError::new(s, "a fold like `fold along x=5`")),
+        }
+    })
+    .map_err(|e| e.shifted(points.len() + 1))?;
+
+    Ok(Input {
+        dots: points,
+        folds: instrs,
+    })
+}
+
+/// Return how many dots are visible after the first fold instruction.
+pub fn part1(input: &Input) -> Result<u32, ParseError> {
+    let first = input
+        .folds
+        .first()
+        .ok_or_else(|| ParseError::whole("there are no folds"))?;
+    let points = execute_instruction(&input.dots, first);
+
+    Ok(points.len().try_into().unwrap())
+}
+
+/// Print out a visualization of the paper after all folds are completed.
+pub fn part2(input: &Input) -> Result<String, ParseError> {
+    if input.dots.is_empty() {
+        return Err(ParseError::whole("there are no dots"));
+    }
+
+    let res = execute_all_folds(&input.dots, &input.folds);
+
+    // Add 1 because the maximum coordinate + 1 is the len
+    let y_size = res.iter().map(|(_, y)| y).max().unwrap() + 1;
+    let x_size = res.iter().map(|(x, _)| x).max().unwrap() + 1;
+
+    let mut grid = Grid::new(x_size.try_into().unwrap(), y_size.try_into().unwrap(), ' ');
+
+    for p in res {
+        let (x, y): (usize, usize) = (p.0.try_into().unwrap(), p.1.try_into().unwrap());
+        grid[(x, y)] = '#';
+    }
+
+    Ok(grid.to_string())
+}
+
+/// Given the initial dots and list of instructions, return the dots after
+/// every fold.
+fn execute_all_folds(points: &[(u32, u32)], instrs: &[Instr]) -> Dots {
+    instrs
+        .iter()
+        .fold(points.to_vec(), |points, i| execute_instruction(&points, i))
+}
+
+/// Given a list of dotted points and an instruction, return a new grid with the
+/// dotted points after executing the instruction.
+fn execute_instruction(v: &[(u32, u32)], i: &Instr) -> Vec<(u32, u32)> {
+    let (mut l, r): (Vec<_>, Vec<_>) = v.iter().partition(|(x, y)| match i {
+        Instr::X(n) => x < n,
+        Instr::Y(n) => y < n,
+    });
+
+    // Filter out the points ON line n, as we don't want them
+    let r = r
+        .iter()
+        .filter(|(x, y)| match i {
+            Instr::X(n) => x != n,
+            Instr::Y(n) => y != n,
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // Now we map r's points to l's dimensions
+    let r_translate: Vec<(u32, u32)> = r
+        .iter()
+        .map(|(x, y)| match i {
+            // Take the distance from the fold, multiply it by 2 (to get to the
+            // fold line, and then shift FROM the fold line), then shift that
+            // much left or up
+            Instr::X(n) => (*x - ((*x - n) * 2), *y),
+            Instr::Y(n) => (*x, *y - ((*y - n) * 2)),
+        })
+        .collect::<Vec<_>>();
+
+    for p in r_translate {
+        if !l.contains(&p) {
+            l.push(p);
+        }
+    }
+
+    l
+}
+
+#[derive(Debug)]
+/// Holds a fold on x or a fold on y instruction.
+pub enum Instr {
+    X(u32),
+    Y(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const SAMPLE_INPUT: &str = fixtures::input(13, 1);
+
+    #[test]
+    fn sample_test() {
+        fixtures::check(13, parse, part1, part2);
+    }
+
+    #[test]
+    fn sample_test_two_steps() {
+        let input = parse(SAMPLE_INPUT).unwrap();
+
+        // perform BOTH steps to ensure we handle X and Y case correctly
+        let points = execute_instruction(&input.dots, input.folds.first().unwrap());
+        let points = execute_instruction(&points, input.folds.get(1).unwrap());
+
+        assert_eq!(16, points.len());
+        assert_eq!(16, execute_all_folds(&input.dots, &input.folds).len());
+    }
+
+    #[test]
+    fn bad_fold() {
+        let e = parse("6,10\n0,14\n\nfold along z=3").unwrap_err();
+        assert_eq!(
+            "line 4: expected a fold like `fold along x=5`, found `fold along z=3`",
+            e.to_string()
+        );
+
+        let e = parse("6,10\n0 14\n\nfold along y=7").unwrap_err();
+        assert_eq!(ParseError::new("0 14", "a dot like `x,y`").shifted(1), e);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(13, day13::parse, day13::part1, day13::part2);
+}
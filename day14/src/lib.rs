@@ -1,17 +1,55 @@
 use std::collections::HashMap;
 
-const NUM_OF_STEPS: i32 = 40;
+use aoc_common::parse::{self, ParseError};
 
-fn main() {
-    println!("{}", run(include_str!("../input.txt")));
+const PART1_STEPS: i32 = 10;
+const PART2_STEPS: i32 = 40;
+
+/// Hold all the data generated when parsing the input string
+#[derive(Debug)]
+pub struct Input {
+    start: HashMap<String, u64>,
+    key: HashMap<String, String>,
+    start_end: (char, char),
 }
 
-/// Given puzzle input, simulate NUM_OF_STEPS steps and take the quantity of the
-/// most common element, subtracting the quantity of the least common element.
-fn run(s: &str) -> u64 {
-    let p = Parsed::new(s);
+/// Given puzzle input, return the pairs in the initial polymer and a HashMap
+/// of pair replacement rules.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let (start, key) = split_input(s)?;
+
+    let (first, last) = (start.chars().next().unwrap(), start.chars().last().unwrap());
+
+    let key = parse_rules(start, key, 2)?
+        .into_iter()
+        .map(|(pair, insert)| (pair.to_string(), insert.to_string()))
+        .collect();
+
+    let start = element_count(to_str_pair_vec(start));
+
+    Ok(Input {
+        start,
+        key,
+        start_end: (first, last),
+    })
+}
 
-    let out = steps(p.start, &p.key, NUM_OF_STEPS);
+/// Simulate PART1_STEPS steps and take the quantity of the most common
+/// element, subtracting the quantity of the least common element.
+pub fn part1(p: &Input) -> u64 {
+    most_minus_least(p, PART1_STEPS)
+}
+
+/// Simulate PART2_STEPS steps and take the quantity of the most common
+/// element, subtracting the quantity of the least common element.
+pub fn part2(p: &Input) -> u64 {
+    most_minus_least(p, PART2_STEPS)
+}
+
+/// Simulate i steps and take the quantity of the most common element,
+/// subtracting the quantity of the least common element.
+fn most_minus_least(p: &Input, i: i32) -> u64 {
+    let out = steps(p.start.clone(), &p.key, i);
     let char_counts = to_char_count(&out, &p.start_end);
 
     char_counts.values().max().unwrap() - char_counts.values().min().unwrap()
@@ -96,12 +134,16 @@ fn to_str_pair_vec(s: &str) -> Vec<String> {
 }
 
 /// Perform i steps on s.
-fn steps(pairs: HashMap<String, u64>, key: &HashMap<&str, &str>, i: i32) -> HashMap<String, u64> {
+fn steps(
+    pairs: HashMap<String, u64>,
+    key: &HashMap<String, String>,
+    i: i32,
+) -> HashMap<String, u64> {
     (0..i).fold(pairs, |c, _| step(c, key))
 }
 
 /// Perform one step on s.
-fn step(pairs: HashMap<String, u64>, key: &HashMap<&str, &str>) -> HashMap<String, u64> {
+fn step(pairs: HashMap<String, u64>, key: &HashMap<String, String>) -> HashMap<String, u64> {
     let mut ans = HashMap::new();
 
     for (pair, count) in pairs {
@@ -120,66 +162,71 @@ fn step(pairs: HashMap<String, u64>, key: &HashMap<&str, &str>) -> HashMap<Strin
     ans
 }
 
-// Hold all the data generated when parsing the input string
-struct Parsed<'a> {
-    start: HashMap<String, u64>,
-    key: HashMap<&'a str, &'a str>,
-    start_end: (char, char),
-}
-
-impl Parsed<'_> {
-    // Given puzzle input, return the initial polymer and a HashMap of pair
-    // replacement rules.
-    fn new(s: &str) -> Parsed {
-        let (start, key) = s.split_once("\n\n").unwrap();
-
-        let (first, last) = (start.chars().next().unwrap(), start.chars().last().unwrap());
+/// Parse the pair insertion rules, which start lines_before lines into the
+/// input. Every pair of elements needs a rule or a step would get stuck.
+fn parse_rules<'a>(
+    poly: &str,
+    rules: &'a str,
+    lines_before: usize,
+) -> Result<HashMap<&'a str, &'a str>, ParseError> {
+    let rules_map: HashMap<_, _> = parse::lines(rules, |s| match s.split_once(" -> ") {
+        Some((pair, insert)) if pair.chars().count() == 2 && insert.chars().count() == 1 => {
+            Ok((pair, insert))
+        }
+        _ => Err(ParseError::new(s, "a rule like `AB -> C`")),
+    })
+    .map_err(|e| e.shifted(lines_before))?
+    .into_iter()
+    .collect();
+
+    let mut elements: Vec<char> = poly
+        .chars()
+        .chain(rules_map.keys().flat_map(|p| p.chars()))
+        .collect();
+    elements.sort_unstable();
+    elements.dedup();
+
+    for a in &elements {
+        for b in &elements {
+            let pair = format!("{}{}", a, b);
+            if !rules_map.contains_key(pair.as_str()) {
+                return Err(ParseError::new(pair, "a rule for every pair of elements")
+                    .shifted(lines_before + rules.lines().count()));
+            }
+        }
+    }
 
-        let start = element_count(to_str_pair_vec(start));
+    Ok(rules_map)
+}
 
-        let key = HashMap::from_iter(key.lines().map(|s| s.split_once(" -> ").unwrap()));
+/// Split puzzle input into the polymer template and the rules after it.
+fn split_input(s: &str) -> Result<(&str, &str), ParseError> {
+    let (poly, rules) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::whole("no blank line after the polymer template"))?;
 
-        Parsed {
-            start,
-            key,
-            start_end: (first, last),
-        }
+    if poly.chars().count() < 2 {
+        return Err(ParseError::new(poly, "a polymer of at least two elements"));
     }
+
+    Ok((poly, rules))
 }
 
 #[cfg(test)]
 mod tests {
-
     use super::*;
+    use aoc_common::fixtures;
 
-    const SAMPLE_INPUT: &str = "\
-NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C";
+    const SAMPLE_INPUT: &str = fixtures::input(14, 1);
 
     #[test]
     fn sample_test() {
-        assert_eq!(2188189693529, run(SAMPLE_INPUT));
+        fixtures::check(14, parse, part1, part2);
     }
 
     #[test]
     fn sample_step() {
-        let p = Parsed::new(SAMPLE_INPUT);
+        let p = parse(SAMPLE_INPUT).unwrap();
         let one = step(p.start, &p.key);
         assert_eq!(element_count(to_str_pair_vec("NCNBCHB")), one);
 
@@ -203,12 +250,25 @@ CN -> C";
 
     #[test]
     fn sample_steps() {
-        let p = Parsed::new(SAMPLE_INPUT);
+        let p = parse(SAMPLE_INPUT).unwrap();
         assert_eq!(
             element_count(to_str_pair_vec(
                 "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"
             )),
-            steps(p.start, &p.key, 4)
+            steps(p.start.clone(), &p.key, 4)
+        );
+    }
+
+    #[test]
+    fn missing_rule() {
+        let e = parse("NNCB\n\nNN -> C\nNC -> B").unwrap_err();
+        assert_eq!("a rule for every pair of elements", e.expected);
+        assert_eq!(Some(5), e.line);
+
+        let e = parse("NNCB\n\nNN -> C\nNC - B").unwrap_err();
+        assert_eq!(
+            ParseError::new("NC - B", "a rule like `AB -> C`").shifted(3),
+            e
         );
     }
 }
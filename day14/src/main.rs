@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(14, day14::parse, day14::part1, day14::part2);
+}
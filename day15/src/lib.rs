@@ -0,0 +1,182 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::ParseError;
+
+const EXTEND_SIZE: usize = 5;
+
+/// The risk level of every position in the cave.
+pub type Input = Grid<u32>;
+
+/// Given puzzle input, return the grid of risk levels
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Grid::parse_digits(s)
+}
+
+/// Return the lowest total risk of any path from top left to bottom right.
+pub fn part1(g: &Input) -> Result<u32, ParseError> {
+    dijkstra(g).ok_or_else(no_path)
+}
+
+/// Return the lowest total risk of any path from top left to bottom right on
+/// the extended grid.
+pub fn part2(g: &Input) -> Result<u32, ParseError> {
+    dijkstra(&extend(g)).ok_or_else(no_path)
+}
+
+/// The error for a cave with no path through it, which only happens when it's
+/// empty
+fn no_path() -> ParseError {
+    ParseError::whole("the cave has no positions")
+}
+
+/// Given a grid from input, extend it EXTEND_SIZE times larger, with each right or
+/// downwards grid increasing all numbers by 1.
+fn extend(g: &Grid<u32>) -> Grid<u32> {
+    let (w, h) = (g.width(), g.height());
+
+    Grid::from_fn(w * EXTEND_SIZE, h * EXTEND_SIZE, |(x, y)| {
+        // Each copy of g is a tile, and tiles are incremented by n following the pattern
+        // 0 1 2 3 4 -> one tile row
+        // 1 2 3 4 5
+        // 2 3 4 5 6
+        // 3 4 5 6 7
+        // 4 5 6 7 8
+        let n = (x / w + y / h) as u32;
+        let i = g[(x % w, y % h)];
+
+        // (i + n + 8) % 9 + 1 => adding 8 in mod 9 is the same as subtracting 1 mod 9. so i + n + 8 == i + n - 1.
+        // This maps i <- [1,9] to i-1 <- [0,8] with modulus. Then, add 1 to shift back to [1,9].
+        // ex when n=0, i + n + 8 = i + 8 = i - 1. add 1 to return to [1,9] with no translation
+        // In general, we get i + 1 + 8 = i + n - 1. then we add 1 to translate to [1,9], bringing i+n
+        ((i + n + 8) % 9) + 1
+    })
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
+struct DistEntry {
+    dist: u32,
+    point: Point,
+}
+
+/// Given a grid of numbers, return the cost of the lowest cost path from the
+/// top right to the bottom left, or None if the grid is empty.
+fn dijkstra(g: &Grid<u32>) -> Option<u32> {
+    let start = (0, 0);
+    let end = (g.width().checked_sub(1)?, g.height().checked_sub(1)?);
+
+    let mut dist = HashMap::from([(start, 0)]);
+    let mut prev = HashMap::new();
+    let mut q = BinaryHeap::from([Reverse(DistEntry {
+        dist: 0,
+        point: start,
+    })]);
+
+    while !q.is_empty() {
+        let p = q.pop().unwrap().0;
+
+        // We popped the end point off the queue! Return the distance
+        if p.point == end {
+            return Some(p.dist);
+        }
+
+        for n in g.neighbors4(p.point) {
+            let new_dist = p.dist + g[n];
+
+            if !dist.contains_key(&n) || new_dist < *dist.get(&n).unwrap() {
+                prev.insert(n, p.point);
+                dist.insert(n, new_dist);
+                q.push(Reverse(DistEntry {
+                    dist: new_dist,
+                    point: n,
+                }))
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const SAMPLE_INPUT: &str = fixtures::input(15, 1);
+
+    const SAMPLE_EXTEND: &str = "\
+11637517422274862853338597396444961841755517295286
+13813736722492484783351359589446246169155735727126
+21365113283247622439435873354154698446526571955763
+36949315694715142671582625378269373648937148475914
+74634171118574528222968563933317967414442817852555
+13191281372421239248353234135946434524615754563572
+13599124212461123532357223464346833457545794456865
+31254216394236532741534764385264587549637569865174
+12931385212314249632342535174345364628545647573965
+23119445813422155692453326671356443778246755488935
+22748628533385973964449618417555172952866628316397
+24924847833513595894462461691557357271266846838237
+32476224394358733541546984465265719557637682166874
+47151426715826253782693736489371484759148259586125
+85745282229685639333179674144428178525553928963666
+24212392483532341359464345246157545635726865674683
+24611235323572234643468334575457944568656815567976
+42365327415347643852645875496375698651748671976285
+23142496323425351743453646285456475739656758684176
+34221556924533266713564437782467554889357866599146
+33859739644496184175551729528666283163977739427418
+35135958944624616915573572712668468382377957949348
+43587335415469844652657195576376821668748793277985
+58262537826937364893714847591482595861259361697236
+96856393331796741444281785255539289636664139174777
+35323413594643452461575456357268656746837976785794
+35722346434683345754579445686568155679767926678187
+53476438526458754963756986517486719762859782187396
+34253517434536462854564757396567586841767869795287
+45332667135644377824675548893578665991468977611257
+44961841755517295286662831639777394274188841538529
+46246169155735727126684683823779579493488168151459
+54698446526571955763768216687487932779859814388196
+69373648937148475914825958612593616972361472718347
+17967414442817852555392896366641391747775241285888
+46434524615754563572686567468379767857948187896815
+46833457545794456865681556797679266781878137789298
+64587549637569865174867197628597821873961893298417
+45364628545647573965675868417678697952878971816398
+56443778246755488935786659914689776112579188722368
+55172952866628316397773942741888415385299952649631
+57357271266846838237795794934881681514599279262561
+65719557637682166874879327798598143881961925499217
+71484759148259586125936169723614727183472583829458
+28178525553928963666413917477752412858886352396999
+57545635726865674683797678579481878968159298917926
+57944568656815567976792667818781377892989248891319
+75698651748671976285978218739618932984172914319528
+56475739656758684176786979528789718163989182927419
+67554889357866599146897761125791887223681299833479";
+
+    #[test]
+    fn extend_test() {
+        assert_eq!(
+            Grid::parse_digits(SAMPLE_EXTEND).unwrap(),
+            extend(&Grid::parse_digits(SAMPLE_INPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn sample_test() {
+        fixtures::check(15, parse, part1, part2);
+    }
+
+    #[test]
+    fn empty_cave() {
+        for s in ["", "\n\n"] {
+            let g = parse(s).unwrap();
+            assert_eq!(Err(no_path()), part1(&g));
+            assert_eq!(Err(no_path()), part2(&g));
+        }
+        assert_eq!(Ok(0), part1(&parse("7").unwrap()));
+    }
+}
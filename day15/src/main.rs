@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(15, day15::parse, day15::part1, day15::part2);
+}
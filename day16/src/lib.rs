@@ -0,0 +1,430 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::parse::ParseError;
+
+// Type IDs
+const LITERAL_TYPE: &str = "100";
+
+const SUM_TYPE: &str = "000";
+const PRODUCT_TYPE: &str = "001";
+const MIN_TYPE: &str = "010";
+const MAX_TYPE: &str = "011";
+const GT_TYPE: &str = "101";
+const LT_TYPE: &str = "110";
+const EQ_TYPE: &str = "111";
+
+// Indices for all packets: versions from [0,2], type ID from [3,5]
+const VERSION_START: usize = 0;
+const VERSION_END: usize = 2;
+const TYPE_START: usize = 3;
+const TYPE_END: usize = 5;
+
+// Literal Packet decoding
+const HEADER: usize = 0;
+const PACKET_LENGTH: usize = 5;
+const LAST_PACKET_HEADER: char = '0';
+
+// Operator Packet decoding
+const TYPE_ID: usize = 6;
+// Len Type ID 0 -> num of bits
+const NUM_OF_BITS: &str = "0";
+const BIT_NUM_START: usize = 7;
+const BIT_NUM_END: usize = 21;
+// Len Type ID 1 -> num of packets
+const NUM_OF_PACKETS: &str = "1";
+const PACKET_NUM_START: usize = 7;
+const PACKET_NUM_END: usize = 17;
+
+// Radixes
+const HEX: u32 = 16;
+const BINARY: u32 = 2;
+
+/// The outermost packet of the transmission.
+#[derive(Debug)]
+pub struct Input(Packet);
+
+/// Given puzzle input, parse into a packet.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let (p, _) = Packet::new(&to_bits(s)?)?;
+
+    Ok(Input(p))
+}
+
+/// Return the sum of the versions of every packet.
+pub fn part1(p: &Input) -> u32 {
+    p.0.version_sum()
+}
+
+/// Return the result of packet evaluation.
+pub fn part2(p: &Input) -> u64 {
+    p.0.eval()
+}
+
+/// Holds a string that only consists of 0s and 1s.
+struct BitString(String);
+
+/// Given a hexadecimal string, return it as a string of bits.
+fn to_bits(s: &str) -> Result<BitString, ParseError> {
+    Ok(BitString(
+        s.trim()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c.to_digit(HEX) {
+                Some(d) => Ok(format!("{:04b}", d)),
+                None => Err(ParseError::new(c, "a hex digit").at_column(i + 1)),
+            })
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+/// Get the bits of s in range, or an error if the packet ends before them.
+fn bits(s: &str, range: RangeInclusive<usize>) -> Result<&str, ParseError> {
+    s.get(range)
+        .ok_or_else(|| ParseError::new(s, "a packet that does not end early"))
+}
+
+/// Read a number out of the bits of s in range.
+fn number(s: &str, range: RangeInclusive<usize>) -> Result<usize, ParseError> {
+    // bits are only ever 0s and 1s, so this can't fail
+    Ok(usize::from_str_radix(bits(s, range)?, BINARY).unwrap())
+}
+
+#[derive(Debug, PartialEq)]
+/// A BITS packet.
+enum Packet {
+    Literal(LiteralPacket),
+    Op(OperatorPacket),
+}
+
+impl Packet {
+    /// Given a BITS binary message, return as a parsed packet and data remainder
+    fn new(s: &BitString) -> Result<(Packet, String), ParseError> {
+        let s = &s.0;
+
+        match bits(s, TYPE_START..=TYPE_END)? {
+            LITERAL_TYPE => {
+                let (p, r) = LiteralPacket::new(&LiteralString(s))?;
+                Ok((Packet::Literal(p), r))
+            }
+            _ => {
+                let (p, r) = OperatorPacket::new(&OperatorString(s))?;
+                Ok((Packet::Op(p), r))
+            }
+        }
+    }
+
+    // Return the sum of all the versions of every packet.
+    fn version_sum(&self) -> u32 {
+        match self {
+            Packet::Literal(p) => p.version,
+            Packet::Op(p) => p.version + p.subpackets.iter().map(|p| p.version_sum()).sum::<u32>(),
+        }
+    }
+
+    // Return the evaluation of this packet.
+    fn eval(&self) -> u64 {
+        match self {
+            Packet::Literal(p) => p.eval(),
+            Packet::Op(p) => p.eval(),
+        }
+    }
+}
+
+/// Hold a bit string that is guaranteed to not have type ID LITERAL_TYPE.
+struct LiteralString<'a>(&'a str);
+
+#[derive(Debug, PartialEq)]
+/// A BITS packet of literal type (typeID = TYPE_LITERAL).
+struct LiteralPacket {
+    version: u32,
+    value: u64,
+}
+
+impl LiteralPacket {
+    /// Given a string of a BITS literal packet, return as a parsed LiteralPacket
+    /// and remaining unread string
+    fn new(s: &LiteralString) -> Result<(LiteralPacket, String), ParseError> {
+        let s = s.0;
+
+        let version = number(s, VERSION_START..=VERSION_END)? as u32;
+
+        let literal_data = s
+            .get((TYPE_END + 1)..)
+            .unwrap_or_default()
+            .chars()
+            .collect::<Vec<_>>();
+        let mut chunk_iter = literal_data.chunks(PACKET_LENGTH);
+
+        let mut value = String::new();
+        loop {
+            let chunk = match chunk_iter.next() {
+                Some(c) if c.len() == PACKET_LENGTH => c,
+                _ => return Err(ParseError::new(s, "a literal that does not end early")),
+            };
+
+            // Push chars from chunk, skipping header bit
+            value.push_str(&chunk.iter().skip(1).collect::<String>());
+
+            // if we just pushed the last packet, break
+            if *chunk.get(HEADER).unwrap() == LAST_PACKET_HEADER {
+                break;
+            }
+        }
+        // collect the remaining unused string
+        let rem = chunk_iter.flatten().collect::<String>();
+
+        let value = u64::from_str_radix(&value, BINARY)
+            .map_err(|_| ParseError::new(s, "a literal that fits in 64 bits"))?;
+
+        Ok((LiteralPacket { version, value }, rem))
+    }
+
+    // Evaluate the literal packet to its value.
+    fn eval(&self) -> u64 {
+        self.value
+    }
+}
+
+/// Holds a bit string that is guaranteed to not have type ID LITERAL_TYPE.
+struct OperatorString<'a>(&'a str);
+
+#[derive(Debug, PartialEq)]
+/// A BITS packet of non-literal type
+struct OperatorPacket {
+    version: u32,
+    op: Operation,
+    subpackets: Vec<Packet>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqTo,
+}
+
+impl OperatorPacket {
+    /// Given an OperatorString, return into a parsed OperatorPacket along with
+    /// data that was unread.
+    fn new(s: &OperatorString) -> Result<(OperatorPacket, String), ParseError> {
+        let s = s.0;
+
+        let version = number(s, VERSION_START..=VERSION_END)? as u32;
+
+        let op = match bits(s, TYPE_START..=TYPE_END)? {
+            SUM_TYPE => Operation::Sum,
+            PRODUCT_TYPE => Operation::Product,
+            MIN_TYPE => Operation::Minimum,
+            MAX_TYPE => Operation::Maximum,
+            GT_TYPE => Operation::GreaterThan,
+            LT_TYPE => Operation::LessThan,
+            EQ_TYPE => Operation::EqTo,
+            _ => unreachable!(),
+        };
+
+        let mut subpackets = Vec::new();
+
+        let rem = match bits(s, TYPE_ID..=TYPE_ID)? {
+            NUM_OF_BITS => {
+                let len = number(s, BIT_NUM_START..=BIT_NUM_END)?;
+
+                let remaining_data = bits(s, (BIT_NUM_END + 1)..=(BIT_NUM_END + len))?;
+                let rem = s.get((BIT_NUM_END + len + 1)..).unwrap_or_default();
+                let mut data = remaining_data.to_string();
+
+                while !data.is_empty() {
+                    // data is a BitString: it is created by splitting up the original data, which is a BitString
+                    let (p, rem_data) = Packet::new(&BitString(data))?;
+                    subpackets.push(p);
+                    data = rem_data;
+                }
+
+                rem.to_string()
+            }
+            NUM_OF_PACKETS => {
+                let packets_to_get = number(s, PACKET_NUM_START..=PACKET_NUM_END)?;
+                let mut remaining_data = s
+                    .get((PACKET_NUM_END + 1)..)
+                    .unwrap_or_default()
+                    .to_string();
+
+                for _ in 0..packets_to_get {
+                    // remaining_data is a BitString - made from existing data which is a BitString
+                    let (p, r) = Packet::new(&BitString(remaining_data))?;
+                    subpackets.push(p);
+                    remaining_data = r;
+                }
+
+                remaining_data
+            }
+            _ => unreachable!(),
+        };
+
+        // Check there's something to evaluate, so eval never has to
+        let operands_ok = match op {
+            Operation::Sum | Operation::Product => true,
+            Operation::Minimum | Operation::Maximum => !subpackets.is_empty(),
+            Operation::GreaterThan | Operation::LessThan | Operation::EqTo => subpackets.len() == 2,
+        };
+        if !operands_ok {
+            return Err(ParseError::new(
+                format!("{} subpackets", subpackets.len()),
+                format!("a {:?} packet with a valid number of subpackets", op),
+            ));
+        }
+
+        Ok((
+            OperatorPacket {
+                version,
+                op,
+                subpackets,
+            },
+            rem,
+        ))
+    }
+
+    // Return the result of evaluation.
+    fn eval(&self) -> u64 {
+        let operand_iter = self.subpackets.iter().map(|p| p.eval());
+
+        match self.op {
+            Operation::Sum => operand_iter.sum(),
+            Operation::Product => operand_iter.product(),
+            Operation::Minimum => operand_iter.min().unwrap(),
+            Operation::Maximum => operand_iter.max().unwrap(),
+            Operation::GreaterThan => {
+                let operands = operand_iter.collect::<Vec<_>>();
+                assert_eq!(2, operands.len());
+
+                if operands.first().unwrap() > operands.get(1).unwrap() {
+                    1
+                } else {
+                    0
+                }
+            }
+            Operation::LessThan => {
+                let operands = operand_iter.collect::<Vec<_>>();
+                assert_eq!(2, operands.len());
+
+                if operands.first().unwrap() < operands.get(1).unwrap() {
+                    1
+                } else {
+                    0
+                }
+            }
+            Operation::EqTo => {
+                let operands = operand_iter.collect::<Vec<_>>();
+                assert_eq!(2, operands.len());
+
+                if operands.first().unwrap() == operands.get(1).unwrap() {
+                    1
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures;
+
+    const SAMPLE_LITERAL: &str = fixtures::input(16, 1);
+    const SAMPLE_OPERATOR_LENTYPE0: &str = fixtures::input(16, 2);
+    const SAMPLE_OPERATOR_LENTYPE1: &str = fixtures::input(16, 3);
+
+    #[test]
+    fn examples() {
+        fixtures::check(16, parse, part1, part2);
+    }
+
+    #[test]
+    fn literal_test() {
+        let ans = Packet::Literal(LiteralPacket {
+            version: 6,
+            value: 2021,
+        });
+
+        assert_eq!(
+            (ans, String::from("000")),
+            Packet::new(&to_bits(SAMPLE_LITERAL).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn operator_lentype0_test() {
+        let ans = Packet::Op(OperatorPacket {
+            version: 1,
+            op: Operation::LessThan,
+            subpackets: vec![
+                Packet::Literal(LiteralPacket {
+                    version: 6,
+                    value: 10,
+                }),
+                Packet::Literal(LiteralPacket {
+                    version: 2,
+                    value: 20,
+                }),
+            ],
+        });
+
+        assert_eq!(
+            (ans, String::from("0000000")),
+            Packet::new(&to_bits(SAMPLE_OPERATOR_LENTYPE0).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn operator_lentype1_test() {
+        let ans = Packet::Op(OperatorPacket {
+            version: 7,
+            op: Operation::Maximum,
+            subpackets: vec![
+                Packet::Literal(LiteralPacket {
+                    version: 2,
+                    value: 1,
+                }),
+                Packet::Literal(LiteralPacket {
+                    version: 4,
+                    value: 2,
+                }),
+                Packet::Literal(LiteralPacket {
+                    version: 1,
+                    value: 3,
+                }),
+            ],
+        });
+
+        assert_eq!(
+            (ans, String::from("00000")),
+            Packet::new(&to_bits(SAMPLE_OPERATOR_LENTYPE1).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn cut_short() {
+        // The literal from SAMPLE_LITERAL, missing its last group
+        let e = parse("D2FE").unwrap_err();
+        assert_eq!("a literal that does not end early", e.expected);
+
+        // An operator packet that promises more bits than there are
+        let e = parse("38006F4529").unwrap_err();
+        assert_eq!("a packet that does not end early", e.expected);
+    }
+
+    #[test]
+    fn bad_packets() {
+        assert_eq!(
+            ParseError::new('G', "a hex digit").at_column(3),
+            parse("C2G0B40A82").unwrap_err()
+        );
+        // A greater than packet with only one subpacket
+        assert_eq!("1 subpackets", parse("D6004428").unwrap_err().token);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(16, day16::parse, day16::part1, day16::part2);
+}
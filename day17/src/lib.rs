@@ -1,14 +1,30 @@
 use std::{cmp::max, ops::RangeInclusive};
 
-fn main() {
-    println!("{}", run(include_str!("../input.txt")));
+use aoc_common::parse::ParseError;
+
+/// The target area.
+pub type Input = Ranges;
+
+/// Parse the target area out of the puzzle input.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Ranges::new(s)
+}
+
+/// Return the highest y position the probe can reach when launched while
+/// still landing within the target range.
+pub fn part1(r: &Input) -> i32 {
+    // shooting straight at the target always lands, so there is a hit
+    hits(r).into_iter().max().unwrap()
 }
 
-/// Given puzzle input, return the highest y position the probe can reach
-/// when launched while still landing within the target range.
-fn run(s: &str) -> usize {
-    let r = Ranges::new(s);
+/// Return how many launch velocities land within the target range.
+pub fn part2(r: &Input) -> usize {
+    hits(r).len()
+}
 
+/// Return the highest y position of every launch velocity that lands within
+/// the target range.
+fn hits(r: &Ranges) -> Vec<i32> {
     // Find the first v_x that results in a final x coord within the x range
     // Final x coord: 1 + 2 + ... + v_x
     let x_min = inv_arith_sum(*r.x.start());
@@ -34,16 +50,8 @@ fn run(s: &str) -> usize {
     let y_max = -r.y.start();
 
     (x_min..x_max)
-        .map(|x| {
-            (*y_min..y_max)
-                .map(|y| r.launch((x, y)))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>()
-        .iter()
-        .flatten()
-        .flatten()
-        .count() // just needed to change this and func type to return usize
+        .flat_map(|x| (*y_min..y_max).filter_map(move |y| r.launch((x, y))))
+        .collect()
 }
 
 /// Solve for n such that n is the first int where 1 + 2 + ... + n > x.
@@ -59,46 +67,34 @@ fn inv_arith_sum(x: i32) -> i32 {
 
 #[derive(Debug, PartialEq)]
 /// Ranges for the target area.
-struct Ranges {
+pub struct Ranges {
     x: RangeInclusive<i32>,
     y: RangeInclusive<i32>,
 }
 
 impl Ranges {
     /// Given puzzle input, parse out into the Ranges we need.
-    fn new(s: &str) -> Ranges {
-        match s
-            .split_whitespace()
-            .skip(2)
-            .map(|s| {
-                match s
-                    .split_once('=')
-                    .unwrap()
-                    .1
-                    .split("..")
-                    .map(|i| {
-                        i.chars()
-                            // There's definitely a better way to do this, but
-                            // clear out the , at the end of x_max
-                            .filter(|&c| c != ',')
-                            .collect::<String>()
-                            .parse()
-                            .unwrap()
-                    })
-                    .collect::<Vec<_>>()[..]
-                {
-                    [x, y] => (x, y),
-                    _ => unreachable!(),
-                }
-            })
-            .collect::<Vec<_>>()[..]
-        {
-            [(x1, x2), (y1, y2)] => Ranges {
-                x: (x1..=x2),
-                y: (y1..=y2),
-            },
-            _ => unreachable!(),
+    fn new(s: &str) -> Result<Ranges, ParseError> {
+        let line = s.trim();
+
+        let (x, y) = line
+            .strip_prefix("target area: ")
+            .and_then(|r| r.split_once(", "))
+            .ok_or_else(|| {
+                ParseError::new(line, "a target like `target area: x=1..2, y=-2..-1`")
+            })?;
+
+        let (x, y) = (range(line, x, "x=")?, range(line, y, "y=")?);
+
+        // Our math only works for a target to the right of and below the launcher
+        if *x.start() <= 0 || *y.end() >= 0 {
+            return Err(ParseError::new(
+                line,
+                "a target with positive x and negative y",
+            ));
         }
+
+        Ok(Ranges { x, y })
     }
 
     /// Given a launch velocity, return the highest Y dimension if the prove
@@ -130,15 +126,34 @@ impl Ranges {
     }
 }
 
+/// Parse one side of the target like `x=20..30`, where part is a piece of line.
+fn range(line: &str, part: &str, prefix: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let err = || {
+        ParseError::new(part, format!("a range like `{}1..2`", prefix))
+            .at_column(line.find(part).unwrap_or(0) + 1)
+    };
+
+    let (start, end) = part
+        .strip_prefix(prefix)
+        .and_then(|r| r.split_once(".."))
+        .ok_or_else(err)?;
+
+    match (start.parse(), end.parse()) {
+        (Ok(start), Ok(end)) if start <= end => Ok(start..=end),
+        _ => Err(err()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::fixtures;
 
-    const SAMPLE_INPUT: &str = "target area: x=20..30, y=-10..-5";
+    const SAMPLE_INPUT: &str = fixtures::input(17, 1);
 
     #[test]
     fn step_test() {
-        let r = Ranges::new(SAMPLE_INPUT);
+        let r = Ranges::new(SAMPLE_INPUT).unwrap();
 
         assert_eq!(Some(3), r.launch((7, 2)));
         assert_eq!(Some(6), r.launch((6, 3)));
@@ -152,11 +167,23 @@ mod tests {
             x: (20..=30),
             y: (-10..=-5),
         };
-        assert_eq!(ans, Ranges::new(SAMPLE_INPUT));
+        assert_eq!(Ok(ans), Ranges::new(SAMPLE_INPUT));
     }
 
     #[test]
     fn sample_test() {
-        assert_eq!(112, run(SAMPLE_INPUT));
+        fixtures::check(17, parse, part1, part2);
+    }
+
+    #[test]
+    fn bad_target() {
+        let e = parse("target area: x=20..30, y=-10.-5").unwrap_err();
+        assert_eq!(
+            "line 1, column 24: expected a range like `y=1..2`, found `y=-10.-5`",
+            e.to_string()
+        );
+
+        let e = parse("target area: x=20..30, y=5..10").unwrap_err();
+        assert_eq!("a target with positive x and negative y", e.expected);
     }
 }
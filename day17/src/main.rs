@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(17, day17::parse, day17::part1, day17::part2);
+}
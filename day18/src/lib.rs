@@ -1,42 +1,61 @@
-use std::collections::VecDeque;
+use std::cmp::max;
 use std::fmt;
 use std::ops::Add;
 
+use aoc_common::parse::{self, ParseError};
+
 const OPEN_CHAR: char = '[';
 const CLOSED_CHAR: char = ']';
 const SEP_CHAR: char = ',';
 const EXPLODE_DEPTH: i32 = 5;
 const SPLIT_MIN: u32 = 10;
 
-fn main() {
-    println!("{}", run(include_str!("../input.txt")));
+/// The shellfish homework, already reduced.
+pub type Input = Vec<ReducedShellNum>;
+
+/// Given a file of shellfish numbers, parse them all into ReducedShellNums.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    parse::lines(s, |i| Ok(BasicShellNum::new(i)?.reduce()))
 }
 
 /// Given a shellfish homework problem, return the magnitude of the final sum
-fn run(s: &str) -> u32 {
-    add_list(s).magnitude()
+pub fn part1(list: &Input) -> Result<u32, ParseError> {
+    Ok(add_list(list)?.magnitude())
 }
 
-/// Given a string holding a list of snailfish numbers, return the result of all
-/// of them added up.
-fn add_list(s: &str) -> ReducedShellNum {
-    let mut nums = VecDeque::from(parse(s));
-    let mut sum = nums.pop_front().unwrap();
+/// Given a shellfish homework problem, return the largest magnitude from adding
+/// two of the snailfish numbers
+pub fn part2(list: &Input) -> u32 {
+    let mut max_mag = 0;
 
-    while !nums.is_empty() {
-        sum = sum.add(nums.pop_front().unwrap());
+    for n1 in list.iter() {
+        for n2 in list.iter().filter(|&n2| n2 != n1) {
+            let curr_mag = n1.clone().add(n2.clone()).magnitude();
+
+            max_mag = max(curr_mag, max_mag);
+        }
     }
 
-    sum
+    max_mag
 }
 
-/// Given a file of shellfish numbers, parse them all into ReducedShellNums.
-fn parse(s: &str) -> Vec<ReducedShellNum> {
-    s.lines().map(|i| BasicShellNum::new(i).reduce()).collect()
+/// Given a list of snailfish numbers, return the result of all of them added
+/// up.
+fn add_list(list: &[ReducedShellNum]) -> Result<ReducedShellNum, ParseError> {
+    let mut nums = list.iter().cloned();
+    let mut sum = nums
+        .next()
+        .ok_or_else(|| ParseError::whole("there are no snailfish numbers"))?;
+
+    for n in nums {
+        sum = sum.add(n);
+    }
+
+    Ok(sum)
 }
 
 /// The tokens in the shellfish number
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 enum Elem {
     Open,
     Close,
@@ -50,17 +69,25 @@ struct BasicShellNum {
     num: Vec<Elem>,
 }
 
-#[derive(Debug)]
-struct ReducedShellNum {
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReducedShellNum {
     num: Vec<Elem>,
 }
 
 impl BasicShellNum {
     /// Given a shell number, tokenize it into a ShellNum
-    fn new(s: &str) -> BasicShellNum {
+    fn new(s: &str) -> Result<BasicShellNum, ParseError> {
+        // Reducing assumes a well formed number, so check it first
+        let chars = s.chars().collect::<Vec<_>>();
+        let end = check_pair(&chars, 0, 1)?;
+        if end != chars.len() {
+            let rest = chars[end..].iter().collect::<String>();
+            return Err(ParseError::new(rest, "the end of the line").at_column(end + 1));
+        }
+
         let mut v = vec![];
 
-        for c in s.chars() {
+        for c in chars {
             v.push(match c {
                 OPEN_CHAR => Elem::Open,
                 CLOSED_CHAR => Elem::Close,
@@ -69,7 +96,7 @@ impl BasicShellNum {
             })
         }
 
-        BasicShellNum { num: v }
+        Ok(BasicShellNum { num: v })
     }
 
     /// Scan for explodes that need to occur. Return false if there are no
@@ -260,6 +287,48 @@ impl BinaryTree {
     }
 }
 
+/// Check that there is a snailfish pair starting at c[i], nested depth pairs
+/// deep, returning the index just after it.
+fn check_pair(c: &[char], i: usize, depth: i32) -> Result<usize, ParseError> {
+    let expect = |i: usize, want: char| match c.get(i) {
+        Some(&got) if got == want => Ok(i + 1),
+        got => Err(ParseError::new(
+            got.map(|g| g.to_string()).unwrap_or_default(),
+            format!("`{}`", want),
+        )
+        .at_column(i + 1)),
+    };
+
+    // explode can only handle pairs of regular numbers at EXPLODE_DEPTH
+    if depth > EXPLODE_DEPTH {
+        return Err(ParseError::new(
+            OPEN_CHAR,
+            format!("pairs nested at most {} deep", EXPLODE_DEPTH),
+        )
+        .at_column(i + 1));
+    }
+
+    let i = expect(i, OPEN_CHAR)?;
+    let i = check_elem(c, i, depth)?;
+    let i = expect(i, SEP_CHAR)?;
+    let i = check_elem(c, i, depth)?;
+    expect(i, CLOSED_CHAR)
+}
+
+/// Check that c[i] is a single digit or a pair inside of a pair depth deep,
+/// returning the index just after it.
+fn check_elem(c: &[char], i: usize, depth: i32) -> Result<usize, ParseError> {
+    match c.get(i) {
+        Some(d) if d.is_ascii_digit() => Ok(i + 1),
+        Some(&OPEN_CHAR) => check_pair(c, i, depth + 1),
+        got => Err(ParseError::new(
+            got.map(|g| g.to_string()).unwrap_or_default(),
+            format!("a digit or `{}`", OPEN_CHAR),
+        )
+        .at_column(i + 1)),
+    }
+}
+
 /// Print a shellnum with the same formatting as the input
 fn print_shell_num(v: &[Elem], f: &mut fmt::Formatter<'_>) -> fmt::Result {
     for e in v {
@@ -299,6 +368,7 @@ impl fmt::Display for Elem {
 mod tests {
 
     use super::*;
+    use aoc_common::fixtures;
 
     const PARSE_EXAMPLES: &str = "\
 [1,2]
@@ -325,31 +395,22 @@ mod tests {
 
     #[test]
     fn big_test() {
-        let list = "\
-[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-
-        let num = add_list(list);
+        let list = fixtures::input(18, 5);
+
+        let num = add_list(&parse(list).unwrap()).unwrap();
 
         assert_eq!(
             "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]",
             format!("{}", num)
         );
 
-        assert_eq!(4140, num.magnitude())
+        fixtures::check(18, parse, part1, part2);
     }
 
     #[test]
     fn magnitute_tests() {
-        let mag_test = |ans, list| assert_eq!(ans, BasicShellNum::new(list).reduce().magnitude());
+        let mag_test =
+            |ans, list| assert_eq!(ans, BasicShellNum::new(list).unwrap().reduce().magnitude());
 
         mag_test(29, "[9,1]");
         mag_test(21, "[1,9]");
@@ -370,19 +431,28 @@ mod tests {
         // List test case 1
         assert_eq!(
             "[[[[1,1],[2,2]],[3,3]],[4,4]]",
-            format!("{}", add_list("[1,1]\n[2,2]\n[3,3]\n[4,4]"))
+            format!(
+                "{}",
+                add_list(&parse(fixtures::input(18, 1)).unwrap()).unwrap()
+            )
         );
 
         // List test case 2
         assert_eq!(
             "[[[[3,0],[5,3]],[4,4]],[5,5]]",
-            format!("{}", add_list("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]"))
+            format!(
+                "{}",
+                add_list(&parse(fixtures::input(18, 2)).unwrap()).unwrap()
+            )
         );
 
         // List test case 3
         assert_eq!(
             "[[[[5,0],[7,4]],[5,5]],[6,6]]",
-            format!("{}", add_list("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"))
+            format!(
+                "{}",
+                add_list(&parse(fixtures::input(18, 3)).unwrap()).unwrap()
+            )
         );
 
         // Large example
@@ -390,19 +460,7 @@ mod tests {
             "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
             format!(
                 "{}",
-                add_list(
-                    "\
-[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
-[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
-[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
-[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
-[7,[5,[[3,8],[1,4]]]]
-[[2,[2,2]],[8,[8,1]]]
-[2,9]
-[1,[[[9,3],9],[[9,0],[0,7]]]]
-[[[5,[7,4]],7],1]
-[[[[4,2],2],6],[8,7]]"
-                )
+                add_list(&parse(fixtures::input(18, 4)).unwrap()).unwrap()
             )
         )
     }
@@ -410,8 +468,10 @@ mod tests {
     #[test]
     /// Test a basic addition between two numbers
     fn basic_add() {
-        let n1 = BasicShellNum::new("[[[[4,3],4],4],[7,[[8,4],9]]]").reduce();
-        let n2 = BasicShellNum::new("[1,1]").reduce();
+        let n1 = BasicShellNum::new("[[[[4,3],4],4],[7,[[8,4],9]]]")
+            .unwrap()
+            .reduce();
+        let n2 = BasicShellNum::new("[1,1]").unwrap().reduce();
 
         assert_eq!(
             "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
@@ -422,7 +482,7 @@ mod tests {
     #[test]
     /// Test a basic split
     fn split_test() {
-        let mut start = BasicShellNum::new("[[[[0,7],4],[1,[0,1]]],[1,1]]");
+        let mut start = BasicShellNum::new("[[[[0,7],4],[1,[0,1]]],[1,1]]").unwrap();
         // lazy hack because I don't want to change my parser to interpret multidigit numbers!
         // Fun fact: that's how my parser worked until I realized I only needed single digit numbers and simplified it!
         // Forgot about test cases! Should've just left it there!
@@ -446,7 +506,7 @@ mod tests {
     // Test explosion test cases
     fn explode_test() {
         for (start, ans) in EXPLODE_EXAMPLES {
-            let mut start = BasicShellNum::new(start);
+            let mut start = BasicShellNum::new(start).unwrap();
 
             start.explode();
 
@@ -459,16 +519,36 @@ mod tests {
     /// back to the same string
     fn parse_test() {
         for num in PARSE_EXAMPLES.split('\n') {
-            assert_eq!(num, format!("{}", BasicShellNum::new(num)));
+            assert_eq!(num, format!("{}", BasicShellNum::new(num).unwrap()));
         }
     }
 
     #[test]
     /// Test joining two shell nums together.
     fn join_test() {
-        let n1 = BasicShellNum::new("[1,2]").reduce();
-        let n2 = BasicShellNum::new("[[3,4],5]").reduce();
+        let n1 = BasicShellNum::new("[1,2]").unwrap().reduce();
+        let n2 = BasicShellNum::new("[[3,4],5]").unwrap().reduce();
 
         assert_eq!("[[1,2],[[3,4],5]]", format!("{}", n1.join(n2)))
     }
+
+    #[test]
+    fn bad_numbers() {
+        let e = parse("[1,1]\n[2,x]").unwrap_err();
+        assert_eq!(
+            "line 2, column 4: expected a digit or `[`, found `x`",
+            e.to_string()
+        );
+
+        let e = parse("[1,1]\n[[[[[[1,2],3],4],5],6],7]").unwrap_err();
+        assert_eq!(
+            ParseError::new('[', "pairs nested at most 5 deep")
+                .at_column(6)
+                .shifted(1),
+            e
+        );
+
+        let e = parse("[1,1]]").unwrap_err();
+        assert_eq!(ParseError::new(']', "the end of the line").at_column(6), e);
+    }
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::cli::main(18, day18::parse, day18::part1, day18::part2);
+}